@@ -6,14 +6,14 @@ use crate::*;
 pub async fn run<B, D, L>(bus: B, mut tx: L, mut delay: D, _irq: ()) -> !
 where
     B: BusOperation,
-    D: DelayNs + Clone,
+    D: DelayNs,
     L: embedded_io::Write
 {
     use ilps22qs::prelude::*;
     use ilps22qs::*;
 
     info!("Configuring the sensor");
-    let mut sensor = Ilps22qs::from_bus(bus, delay.clone());
+    let mut sensor = Ilps22qs::from_bus(bus);
 
     // boot time
     delay.delay_ms(10).await;
@@ -28,9 +28,9 @@ where
     }
 
     // Restore default configuration
-    sensor.init_set(Init::Reset).await.unwrap();
+    sensor.init_set(Init::Reset, &mut delay).await.unwrap();
     loop {
-        if sensor.status_get().await.unwrap().sw_reset == 0 {
+        if !sensor.status_get().await.unwrap().sw_reset {
             break;
         }
     }
@@ -38,7 +38,7 @@ where
     // Disable AH/QVAR to save power consumption
     sensor.ah_qvar_disable().await.unwrap();
     // Set bdu and if_inc, recomended for driver usage
-    sensor.init_set(Init::DrvRdy).await.unwrap();
+    sensor.init_set(Init::DrvRdy, &mut delay).await.unwrap();
 
     // Select bus interface
     sensor
@@ -50,7 +50,7 @@ where
 
     // Set output Data rate
     let md = Md {
-        interleaved_mode: 0,
+        interleaved_mode: false,
         fs: Fs::_1260hpa,
         odr: Odr::_10hz,
         avg: Avg::_16,
@@ -62,7 +62,7 @@ where
     sensor
         .fifo_mode_set(&FifoMd {
             operation: Operation::Stream,
-            watermark: 32,
+            watermark: Watermark::from_const::<32>(),
         })
         .await.unwrap();
 
@@ -71,17 +71,20 @@ where
     loop {
         // Read output only if new values are available
         if let Ok(all_sources) = sensor.all_sources_get().await {
-            if all_sources.fifo_th > 0 {
-                let level = sensor.fifo_level_get().await.unwrap_or(0);
-                if let Ok(()) = sensor.fifo_data_get(level, &md, &mut data).await {
+            if all_sources.fifo_th {
+                // Never ask for more samples than the buffer holds, the rest stays queued
+                let level = sensor
+                    .fifo_level_get()
+                    .await
+                    .unwrap_or(0)
+                    .min(data.len() as u8);
+                if let Ok(n) = sensor.fifo_data_get(level, &mut data).await {
                     writeln!(tx, "--- FIFO salmples").unwrap();
-                    for i in 0..level {
-                        if data[i as usize].lsb == 0 {
-                            writeln!(tx, "{:02}: pressure [hPa]:{:6.2}", i, data[i as usize].hpa)
-                                .unwrap();
+                    for (i, sample) in data[..n].iter().enumerate() {
+                        if sample.lsb == 0 {
+                            writeln!(tx, "{:02}: pressure [hPa]:{:6.2}", i, sample.hpa).unwrap();
                         } else {
-                            writeln!(tx, "{:02}: AH_QVAR lsb: {}", i, data[i as usize].hpa)
-                                .unwrap();
+                            writeln!(tx, "{:02}: AH_QVAR lsb: {}", i, sample.hpa).unwrap();
                         }
                     }
                     writeln!(tx).unwrap();
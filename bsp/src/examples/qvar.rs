@@ -6,14 +6,14 @@ use crate::*;
 pub async fn run<B, D, L>(bus: B, mut tx: L, mut delay: D, _irq: ()) -> !
 where
     B: BusOperation,
-    D: DelayNs + Clone,
+    D: DelayNs,
     L: embedded_io::Write
 {
     use ilps22qs::prelude::*;
     use ilps22qs::*;
 
     info!("Configuring the sensor");
-    let mut sensor = Ilps22qs::from_bus(bus, delay.clone());
+    let mut sensor = Ilps22qs::from_bus(bus);
 
     // boot time
     delay.delay_ms(10).await;
@@ -28,15 +28,15 @@ where
     }
 
     // Restore default configuration
-    sensor.init_set(Init::Reset).await.unwrap();
+    sensor.init_set(Init::Reset, &mut delay).await.unwrap();
     loop {
-        if sensor.status_get().await.unwrap().sw_reset == 0 {
+        if !sensor.status_get().await.unwrap().sw_reset {
             break;
         }
     }
 
     // Set bdu and if_inc, recomended for driver usage
-    sensor.init_set(Init::DrvRdy).await.unwrap();
+    sensor.init_set(Init::DrvRdy, &mut delay).await.unwrap();
 
     // Select bus interface
     sensor
@@ -56,13 +56,13 @@ where
     sensor.mode_set(&md).await.unwrap();
 
     // Enable AH/QVAR function
-    sensor.ah_qvar_en_set(1).await.unwrap();
+    sensor.ah_qvar_en_set(true).await.unwrap();
 
     // Read samples in polling mode (no int)
     loop {
         // Read output only if new values are available
         if let Ok(all_sources) = sensor.all_sources_get().await {
-            if all_sources.drdy_pres == 1 {
+            if all_sources.drdy_pres {
                 if let Ok(data) = sensor.ah_qvar_data_get().await {
                     writeln!(tx, "QVAR [mV]: {:6.2} [LSB]: {}", data.mv, data.lsb).unwrap();
                 }
@@ -6,14 +6,14 @@ use crate::*;
 pub async fn run<B, D, L>(bus: B, mut tx: L, mut delay: D, _irq: ()) -> !
 where
     B: BusOperation,
-    D: DelayNs + Clone,
+    D: DelayNs,
     L: embedded_io::Write
 {
     use ilps22qs::prelude::*;
     use ilps22qs::*;
 
     info!("Configuring the sensor");
-    let mut sensor = Ilps22qs::from_bus(bus, delay.clone());
+    let mut sensor = Ilps22qs::from_bus(bus);
 
     // boot time
     delay.delay_ms(10).await;
@@ -28,9 +28,9 @@ where
     }
 
     // Restore default configuration
-    sensor.init_set(Init::Reset).await.unwrap();
+    sensor.init_set(Init::Reset, &mut delay).await.unwrap();
     loop {
-        if sensor.status_get().await.unwrap().sw_reset == 0 {
+        if !sensor.status_get().await.unwrap().sw_reset {
             break;
         }
     }
@@ -39,7 +39,7 @@ where
     sensor.ah_qvar_disable().await.unwrap();
 
     // Set bdu and if_inc, recomended for driver usage
-    sensor.init_set(Init::DrvRdy).await.unwrap();
+    sensor.init_set(Init::DrvRdy, &mut delay).await.unwrap();
 
     // Select bus interface
     sensor
@@ -51,7 +51,7 @@ where
 
     // Set Output Data rate
     let md = Md {
-        interleaved_mode: 0,
+        interleaved_mode: false,
         fs: Fs::_1260hpa,
         odr: Odr::_4hz,
         avg: Avg::_16,
@@ -63,8 +63,8 @@ where
     loop {
         // Read output only if new values are available
         if let Ok(all_sources) = sensor.all_sources_get().await {
-            if all_sources.drdy_pres == 1 || all_sources.drdy_temp == 1 {
-                if let Ok(data) = sensor.data_get(&md).await {
+            if all_sources.drdy_pres || all_sources.drdy_temp {
+                if let Ok(data) = sensor.data_get().await {
                     if data.ah_qvar.lsb == 0 {
                         writeln!(
                             tx,
@@ -0,0 +1,63 @@
+/*
+ * C declarations of the ilps22qs-rs `ffi` feature.
+ *
+ * The context is laid out like the first fields of ST's stmdev_ctx_t, so the platform
+ * callbacks of an existing project can be reused. Every function returns ILPS22QS_RS_OK
+ * on success or one of the negative ILPS22QS_RS_ERR_* codes.
+ */
+
+#ifndef ILPS22QS_RS_H
+#define ILPS22QS_RS_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define ILPS22QS_RS_OK            0
+#define ILPS22QS_RS_ERR_BUS      -1
+#define ILPS22QS_RS_ERR_ARG      -2
+#define ILPS22QS_RS_ERR_ID       -3
+#define ILPS22QS_RS_ERR_BOOT     -4
+#define ILPS22QS_RS_ERR_RESET    -5
+#define ILPS22QS_RS_ERR_TIMEOUT  -6
+
+typedef int32_t (*ilps22qs_rs_write_ptr)(void *handle, uint8_t reg, const uint8_t *data, uint16_t len);
+typedef int32_t (*ilps22qs_rs_read_ptr)(void *handle, uint8_t reg, uint8_t *data, uint16_t len);
+typedef void (*ilps22qs_rs_mdelay_ptr)(uint32_t millisec);
+
+typedef struct {
+  ilps22qs_rs_write_ptr write_reg;
+  ilps22qs_rs_read_ptr read_reg;
+  ilps22qs_rs_mdelay_ptr mdelay; /* needed by ilps22qs_rs_init */
+  void *handle;
+} ilps22qs_rs_ctx_t;
+
+typedef struct {
+  uint8_t interleaved_mode; /* 1 to enable the interleaved AH/QVAR mode */
+  uint8_t fs;               /* 0: 1260 hPa, 1: 4060 hPa */
+  uint8_t odr;              /* 0: one-shot, 1: 1 Hz ... 8: 200 Hz */
+  uint8_t avg;              /* 0: 4 samples ... 7: 512 samples */
+  uint8_t lpf;              /* 0: disabled, 1: ODR/4, 3: ODR/9 */
+} ilps22qs_rs_md_t;
+
+typedef struct {
+  float pressure_hpa;
+  int32_t pressure_raw;
+  float temperature_deg_c;
+  int32_t ah_qvar_lsb;
+  int16_t temperature_raw;
+} ilps22qs_rs_data_t;
+
+int32_t ilps22qs_rs_init(const ilps22qs_rs_ctx_t *ctx);
+int32_t ilps22qs_rs_mode_set(const ilps22qs_rs_ctx_t *ctx, const ilps22qs_rs_md_t *md);
+int32_t ilps22qs_rs_mode_get(const ilps22qs_rs_ctx_t *ctx, ilps22qs_rs_md_t *md);
+int32_t ilps22qs_rs_data_get(const ilps22qs_rs_ctx_t *ctx, const ilps22qs_rs_md_t *md,
+                             ilps22qs_rs_data_t *data);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ILPS22QS_RS_H */
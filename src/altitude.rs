@@ -0,0 +1,383 @@
+//! Barometric altitude helpers.
+//!
+//! The conversions follow the International Standard Atmosphere (ISA) troposphere model and
+//! operate on converted pressure in hPa, so they are shared by the asynchronous and blocking
+//! drivers. [`AltitudeEstimator`] fuses the pressure-derived altitude with an optional vertical
+//! acceleration into a smooth altitude and vertical speed; external sensors plug in through
+//! [`FusionSource`].
+//!
+//! The ISA conversions need `powf`, which `core` does not provide: enable the `libm` or the
+//! faster but less accurate `micromath` feature to get [`pressure_to_altitude`],
+//! [`hypsometric_altitude`], [`density_altitude`], the QNH/QFE conversions and
+//! [`RelativeAltitude`].
+
+/// Standard sea-level pressure, in hPa.
+pub const SEA_LEVEL_HPA: f32 = 1013.25;
+
+#[cfg(any(feature = "libm", feature = "micromath"))]
+use crate::math;
+
+/// Converts a pressure to an altitude above the level where the pressure is `sea_level_hpa`.
+///
+/// # Parameters
+///
+/// * `hpa`: The measured pressure, in hPa.
+/// * `sea_level_hpa`: The reference pressure, usually [`SEA_LEVEL_HPA`] or the local QNH.
+///
+/// # Returns
+///
+/// * `f32`: The altitude in meters.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub fn pressure_to_altitude(hpa: f32, sea_level_hpa: f32) -> f32 {
+    44_330.0 * (1.0 - math::powf(hpa / sea_level_hpa, 1.0 / 5.255))
+}
+
+/// Converts a pressure to an altitude using the measured air temperature.
+///
+/// The standard-atmosphere conversion of [`pressure_to_altitude`] assumes a 15 °C sea-level
+/// temperature. The hypsometric formula replaces it with the actual temperature at the device,
+/// either the temperature output of the sensor or an outside air temperature (OAT) from another
+/// probe, which improves the accuracy of outdoor devices on hot or cold days. The sensor
+/// temperature follows the air only if the device does not heat up its surroundings.
+///
+/// # Parameters
+///
+/// * `hpa`: The measured pressure, in hPa.
+/// * `sea_level_hpa`: The reference pressure, usually [`SEA_LEVEL_HPA`] or the local QNH.
+/// * `temperature_deg_c`: The air temperature at the device, in °C.
+///
+/// # Returns
+///
+/// * `f32`: The altitude in meters.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub fn hypsometric_altitude(hpa: f32, sea_level_hpa: f32, temperature_deg_c: f32) -> f32 {
+    (math::powf(sea_level_hpa / hpa, 1.0 / 5.257) - 1.0) * (temperature_deg_c + 273.15) / 0.0065
+}
+
+/// Computes the density altitude, the ISA altitude with the same air density as the device.
+///
+/// The density altitude is the pressure altitude corrected for the deviation of the air
+/// temperature from the standard atmosphere. It drives the performance of wings, rotors and
+/// propellers: on a hot day an airfield behaves as if it were higher. Dry air is assumed, so
+/// humidity, which slightly raises the density altitude, is not accounted for.
+///
+/// # Parameters
+///
+/// * `hpa`: The measured (station) pressure, in hPa.
+/// * `temperature_deg_c`: The outside air temperature at the device, in °C.
+///
+/// # Returns
+///
+/// * `f32`: The density altitude in meters.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub fn density_altitude(hpa: f32, temperature_deg_c: f32) -> f32 {
+    let density_ratio = (hpa / SEA_LEVEL_HPA) * (288.15 / (temperature_deg_c + 273.15));
+    44_330.8 * (1.0 - math::powf(density_ratio, 0.234_969))
+}
+
+/// Reduces a QFE to the QNH altimeter setting.
+///
+/// The QFE is the pressure at the field elevation; the QNH is the same pressure reduced to mean
+/// sea level along the standard atmosphere, so that [`pressure_to_altitude`] with the QNH as
+/// reference reads the field elevation on the ground.
+///
+/// # Parameters
+///
+/// * `qfe_hpa`: The pressure at the field elevation, in hPa.
+/// * `elevation_m`: The field elevation above mean sea level, in meters.
+///
+/// # Returns
+///
+/// * `f32`: The QNH, in hPa.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub fn qnh_from_qfe(qfe_hpa: f32, elevation_m: f32) -> f32 {
+    qfe_hpa / isa_pressure_ratio(elevation_m)
+}
+
+/// Converts a QNH altimeter setting to the QFE at the field elevation.
+///
+/// This is the inverse of [`qnh_from_qfe`].
+///
+/// # Parameters
+///
+/// * `qnh_hpa`: The QNH, in hPa.
+/// * `elevation_m`: The field elevation above mean sea level, in meters.
+///
+/// # Returns
+///
+/// * `f32`: The QFE, in hPa.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub fn qfe_from_qnh(qnh_hpa: f32, elevation_m: f32) -> f32 {
+    qnh_hpa * isa_pressure_ratio(elevation_m)
+}
+
+/// Converts the pressure measured by the sensor to the QFE.
+///
+/// The sensor is often mounted above the field reference point, e.g. on a mast or in an upper
+/// floor; the measured station pressure is then brought down to the field level along the
+/// standard atmosphere. With the sensor at the field level the QFE is the station pressure.
+///
+/// # Parameters
+///
+/// * `station_hpa`: The pressure measured by the sensor, in hPa.
+/// * `sensor_height_m`: The height of the sensor above the field reference point, in meters.
+///
+/// # Returns
+///
+/// * `f32`: The QFE, in hPa.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub fn qfe_from_station(station_hpa: f32, sensor_height_m: f32) -> f32 {
+    station_hpa / isa_pressure_ratio(sensor_height_m)
+}
+
+/// Returns the ISA ratio between the pressure at `altitude_m` and the sea-level pressure.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+fn isa_pressure_ratio(altitude_m: f32) -> f32 {
+    math::powf(1.0 - altitude_m / 44_330.8, 5.255_88)
+}
+
+/// Height change relative to a tared pressure.
+///
+/// The `RelativeAltitude` helper is zeroed with [`RelativeAltitude::tare`] at the current
+/// pressure, e.g. on the ground before take-off or at the bottom of a staircase, and then
+/// reports the height change in meters. Until it is tared, no height is reported.
+///
+/// ```ignore
+/// let mut rel = RelativeAltitude::new();
+/// rel.tare(sensor.data_get().await?.pressure.hpa);
+/// let height_m = rel.height_m(sensor.data_get().await?.pressure.hpa);
+/// ```
+#[cfg(any(feature = "libm", feature = "micromath"))]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct RelativeAltitude {
+    base_m: Option<f32>,
+}
+
+#[cfg(any(feature = "libm", feature = "micromath"))]
+impl RelativeAltitude {
+    /// Creates a helper that has not been tared yet.
+    pub const fn new() -> Self {
+        Self { base_m: None }
+    }
+
+    /// Zeros the helper at the pressure `hpa`.
+    pub fn tare(&mut self, hpa: f32) {
+        self.base_m = Some(pressure_to_altitude(hpa, SEA_LEVEL_HPA));
+    }
+
+    /// Clears the tared reference.
+    pub fn reset(&mut self) {
+        self.base_m = None;
+    }
+
+    /// Returns `true` once the helper has been tared.
+    pub const fn is_tared(&self) -> bool {
+        self.base_m.is_some()
+    }
+
+    /// Returns the height change in meters at the pressure `hpa`, or `None` if not tared.
+    ///
+    /// Positive values are above the tared level.
+    pub fn height_m(&self, hpa: f32) -> Option<f32> {
+        self.base_m
+            .map(|base_m| pressure_to_altitude(hpa, SEA_LEVEL_HPA) - base_m)
+    }
+}
+
+/// Represents the output of [`AltitudeEstimator`].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct AltitudeEstimate {
+    /// The filtered altitude, in meters.
+    pub altitude_m: f32,
+    /// The vertical speed, in meters per second; positive when climbing.
+    pub vertical_speed_mps: f32,
+}
+
+/// Kalman filter estimating altitude and vertical speed.
+///
+/// The `AltitudeEstimator` tracks a constant-velocity model fed by pressure-derived altitude.
+/// An optional vertical acceleration (gravity removed, positive upwards) from an IMU can be
+/// supplied with each update to drive the prediction step, which greatly reduces the lag of
+/// the vertical speed, as needed by variometers and drone altitude hold.
+///
+/// ```ignore
+/// let mut est = AltitudeEstimator::new(0.5, 0.3);
+/// let hpa = sensor.data_get().await?.pressure.hpa;
+/// let out = est.update(pressure_to_altitude(hpa, SEA_LEVEL_HPA), 0.04, None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AltitudeEstimator {
+    estimate: Option<AltitudeEstimate>,
+    covariance: [[f32; 2]; 2],
+    accel_var: f32,
+    altitude_var: f32,
+}
+
+impl AltitudeEstimator {
+    /// Creates an estimator.
+    ///
+    /// # Parameters
+    ///
+    /// * `accel_noise_mps2`: The standard deviation of the unmodelled vertical acceleration,
+    ///   or of the acceleration input when one is supplied, in m/s². Larger values follow
+    ///   manoeuvres faster at the cost of more noise.
+    /// * `altitude_noise_m`: The standard deviation of the altitude measurements, in meters.
+    pub const fn new(accel_noise_mps2: f32, altitude_noise_m: f32) -> Self {
+        Self {
+            estimate: None,
+            covariance: [[0.0; 2]; 2],
+            accel_var: accel_noise_mps2 * accel_noise_mps2,
+            altitude_var: altitude_noise_m * altitude_noise_m,
+        }
+    }
+
+    /// Clears the estimate; the next update restarts from its measurement.
+    pub fn reset(&mut self) {
+        self.estimate = None;
+    }
+
+    /// Returns the latest estimate, or `None` before the first update.
+    pub const fn estimate(&self) -> Option<AltitudeEstimate> {
+        self.estimate
+    }
+
+    /// Feeds an altitude measurement into the filter.
+    ///
+    /// # Parameters
+    ///
+    /// * `altitude_m`: The pressure-derived altitude, in meters.
+    /// * `dt_s`: The time elapsed since the previous update, in seconds.
+    /// * `accel_mps2`: The vertical acceleration over `dt_s`, if available, in m/s².
+    ///
+    /// # Returns
+    ///
+    /// * `AltitudeEstimate`: The updated altitude and vertical speed.
+    pub fn update(
+        &mut self,
+        altitude_m: f32,
+        dt_s: f32,
+        accel_mps2: Option<f32>,
+    ) -> AltitudeEstimate {
+        let Some(AltitudeEstimate {
+            altitude_m: h,
+            vertical_speed_mps: v,
+        }) = self.estimate
+        else {
+            let estimate = AltitudeEstimate {
+                altitude_m,
+                vertical_speed_mps: 0.0,
+            };
+            self.estimate = Some(estimate);
+            self.covariance = [[self.altitude_var, 0.0], [0.0, self.altitude_var]];
+            return estimate;
+        };
+
+        // Prediction
+        let a = accel_mps2.unwrap_or(0.0);
+        let dt2 = dt_s * dt_s;
+        let h = h + v * dt_s + 0.5 * a * dt2;
+        let v = v + a * dt_s;
+
+        let [[p00, p01], [_, p11]] = self.covariance;
+        let q = self.accel_var;
+        let p00 = p00 + dt_s * (2.0 * p01 + dt_s * p11) + q * dt2 * dt2 / 4.0;
+        let p01 = p01 + dt_s * p11 + q * dt2 * dt_s / 2.0;
+        let p11 = p11 + q * dt2;
+
+        self.estimate = Some(AltitudeEstimate {
+            altitude_m: h,
+            vertical_speed_mps: v,
+        });
+        self.covariance = [[p00, p01], [p01, p11]];
+        self.correct(altitude_m, self.altitude_var)
+    }
+
+    /// Feeds an altitude measurement into the filter, completed by the data of `source`.
+    ///
+    /// The vertical acceleration of `source`, if any, drives the prediction step, and its
+    /// altitude fix, if any, is fused after the pressure-derived altitude.
+    ///
+    /// # Parameters
+    ///
+    /// * `altitude_m`: The pressure-derived altitude, in meters.
+    /// * `dt_s`: The time elapsed since the previous update, in seconds.
+    /// * `source`: The external sensors to fuse.
+    ///
+    /// # Returns
+    ///
+    /// * `AltitudeEstimate`: The updated altitude and vertical speed.
+    pub fn update_with<S: FusionSource>(
+        &mut self,
+        altitude_m: f32,
+        dt_s: f32,
+        source: &mut S,
+    ) -> AltitudeEstimate {
+        let estimate = self.update(altitude_m, dt_s, source.vertical_accel_mps2());
+        match source.altitude_fix() {
+            Some(fix) => self.correct(fix.altitude_m, fix.noise_m * fix.noise_m),
+            None => estimate,
+        }
+    }
+
+    fn correct(&mut self, altitude_m: f32, var: f32) -> AltitudeEstimate {
+        let Some(AltitudeEstimate {
+            altitude_m: h,
+            vertical_speed_mps: v,
+        }) = self.estimate
+        else {
+            return AltitudeEstimate::default();
+        };
+        let [[p00, p01], [_, p11]] = self.covariance;
+
+        let s = p00 + var;
+        let (k0, k1) = (p00 / s, p01 / s);
+        let innovation = altitude_m - h;
+
+        let estimate = AltitudeEstimate {
+            altitude_m: h + k0 * innovation,
+            vertical_speed_mps: v + k1 * innovation,
+        };
+        let p01_new = (1.0 - k0) * p01;
+        self.covariance = [[(1.0 - k0) * p00, p01_new], [p01_new, p11 - k1 * p01]];
+        self.estimate = Some(estimate);
+        estimate
+    }
+}
+
+/// Represents an absolute altitude measurement from an external sensor, e.g. a GNSS receiver.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct AltitudeFix {
+    /// The measured altitude, in meters, on the same datum as the pressure-derived altitude.
+    pub altitude_m: f32,
+    /// The standard deviation of the measurement, in meters.
+    pub noise_m: f32,
+}
+
+/// External sensors fused by [`AltitudeEstimator::update_with`].
+///
+/// Implement this trait on the application side to feed IMU or GNSS data into the estimator.
+/// Both methods are polled once per update and default to `None`; `()` implements the trait
+/// with no external data.
+///
+/// ```ignore
+/// struct Imu<'a>(&'a mut Lsm6dsox);
+///
+/// impl FusionSource for Imu<'_> {
+///     fn vertical_accel_mps2(&mut self) -> Option<f32> {
+///         self.0.vertical_accel().ok()
+///     }
+/// }
+/// ```
+pub trait FusionSource {
+    /// Returns the vertical acceleration (gravity removed, positive upwards), in m/s².
+    fn vertical_accel_mps2(&mut self) -> Option<f32> {
+        None
+    }
+
+    /// Returns a new absolute altitude measurement, if one is available.
+    fn altitude_fix(&mut self) -> Option<AltitudeFix> {
+        None
+    }
+}
+
+impl FusionSource for () {}
@@ -0,0 +1,154 @@
+//! Barograph recording the pressure altitude of a flight.
+//!
+//! [`Barograph`] samples the pressure altitude at a fixed cadence and keeps timestamped
+//! [`Fix`]es for host tooling. Each fix can be rendered as an IGC `B` record with
+//! [`Fix::igc_b_record`], so a track file can be assembled without any conversion on the host.
+//!
+//! The pressure altitude is referenced to the standard sea-level pressure, as required by the
+//! IGC format, and needs the `libm` or `micromath` feature.
+
+use crate::altitude::{SEA_LEVEL_HPA, pressure_to_altitude};
+
+/// Length of an IGC `B` record, without the line terminator.
+pub const IGC_B_RECORD_LEN: usize = 35;
+
+/// Represents a timestamped pressure altitude recorded by a [`Barograph`].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Fix {
+    /// The UTC time of the fix, in seconds; only the time of day is used by the IGC record.
+    pub time_s: u32,
+    /// The pressure altitude, in meters, referenced to [`SEA_LEVEL_HPA`].
+    pub pressure_altitude_m: f32,
+}
+
+impl Fix {
+    /// Renders the fix as an IGC `B` record.
+    ///
+    /// The barograph has no position, so the latitude and longitude are zero, the fix validity
+    /// is `V` (no GNSS fix) and the GNSS altitude is zero. The pressure altitude is rounded to
+    /// the meter and clamped to the five characters of the field.
+    pub fn igc_b_record(&self) -> [u8; IGC_B_RECORD_LEN] {
+        let mut record = *b"B0000000000000N00000000EV0000000000";
+
+        let time_of_day = self.time_s % 86_400;
+        let hhmmss = time_of_day / 3600 * 10_000 + time_of_day % 3600 / 60 * 100 + time_of_day % 60;
+        write_digits(record.get_mut(1..7), hhmmss);
+
+        let altitude_m = self.pressure_altitude_m;
+        let altitude_m = (altitude_m + 0.5f32.copysign(altitude_m)) as i32;
+        if altitude_m < 0 {
+            if let Some(sign) = record.get_mut(25) {
+                *sign = b'-';
+            }
+            write_digits(record.get_mut(26..30), altitude_m.unsigned_abs().min(9_999));
+        } else {
+            write_digits(
+                record.get_mut(25..30),
+                altitude_m.unsigned_abs().min(99_999),
+            );
+        }
+
+        record
+    }
+}
+
+/// Writes `value` in decimal into `field`, right-aligned and zero-padded.
+fn write_digits(field: Option<&mut [u8]>, mut value: u32) {
+    for digit in field.into_iter().flatten().rev() {
+        *digit = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+/// Barograph holding up to `N` fixes.
+///
+/// A fix is recorded every `interval_s` seconds from the first sample. Once `N` fixes are held,
+/// recording stops so that the start of the flight is never overwritten.
+///
+/// ```ignore
+/// let mut barograph = Barograph::<4096>::new(4);
+/// loop {
+///     let hpa = sensor.data_get()?.pressure.hpa;
+///     barograph.update(rtc.utc_seconds(), hpa);
+/// }
+/// for fix in barograph.fixes() {
+///     uart.write_all(&fix.igc_b_record())?;
+///     uart.write_all(b"\r\n")?;
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Barograph<const N: usize> {
+    fixes: [Fix; N],
+    len: usize,
+    interval_s: u32,
+    next_s: Option<u32>,
+}
+
+impl<const N: usize> Barograph<N> {
+    /// Creates an empty barograph recording a fix every `interval_s` seconds.
+    ///
+    /// An interval of `0` records every sample.
+    pub const fn new(interval_s: u32) -> Self {
+        const { assert!(N > 0, "barograph capacity must not be zero") };
+        Self {
+            fixes: [Fix {
+                time_s: 0,
+                pressure_altitude_m: 0.0,
+            }; N],
+            len: 0,
+            interval_s,
+            next_s: None,
+        }
+    }
+
+    /// Feeds a pressure sample taken at `time_s`.
+    ///
+    /// Returns `true` if a fix was recorded.
+    pub fn update(&mut self, time_s: u32, hpa: f32) -> bool {
+        if self.next_s.is_some_and(|next_s| time_s < next_s) {
+            return false;
+        }
+        let Some(slot) = self.fixes.get_mut(self.len) else {
+            return false;
+        };
+
+        *slot = Fix {
+            time_s,
+            pressure_altitude_m: pressure_to_altitude(hpa, SEA_LEVEL_HPA),
+        };
+        self.len += 1;
+        self.next_s = Some(time_s.saturating_add(self.interval_s));
+        true
+    }
+
+    /// Clears the recorded fixes.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.next_s = None;
+    }
+
+    /// Returns the recording interval, in seconds.
+    pub const fn interval_s(&self) -> u32 {
+        self.interval_s
+    }
+
+    /// Returns the number of recorded fixes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no fix is recorded.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` once the barograph stopped recording for lack of space.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the recorded fixes, oldest first.
+    pub fn fixes(&self) -> impl Iterator<Item = &Fix> + '_ {
+        self.fixes.iter().take(self.len)
+    }
+}
@@ -0,0 +1,132 @@
+//! Transport splitting long transactions for buses with a limited transfer size.
+
+use super::super::{BusOperation, bisync, prelude::Reg};
+
+/// Largest payload carried by a single write issued by [`Chunked`].
+///
+/// Writes are copied into a stack buffer to prepend the register address, so their chunk size is
+/// the smaller of this value and the configured maximum.
+pub const MAX_WRITE_CHUNK: usize = 32;
+
+/// First and last register of the FIFO output window.
+const FIFO_FIRST: u8 = Reg::FifoDataOutPressXl as u8;
+const FIFO_LAST: u8 = Reg::FifoDataOutPressH as u8;
+
+/// Transport capping the number of bytes moved by one bus transaction.
+///
+/// Some HALs and I2C bridges cannot transfer more than a few bytes at once. `Chunked` splits
+/// every register read and write into transactions of at most `max_len` payload bytes, starting
+/// each chunk at the address the device auto-increment would have reached. Reads from the FIFO
+/// output registers roll back to `FIFO_DATA_OUT_PRESS_XL` after `FIFO_DATA_OUT_PRESS_H`, as the
+/// device does, so FIFO bursts are split on any boundary.
+///
+/// ```ignore
+/// let bus = Chunked::new(I2cBus::new(i2c, I2CAddress::SD0High as SevenBitAddress), 16);
+/// let mut sensor = Ilps22qs::from_bus(bus);
+/// ```
+pub struct Chunked<B> {
+    /// The wrapped bus.
+    pub bus: B,
+    max_len: usize,
+}
+
+impl<B> Chunked<B> {
+    /// Creates a transport splitting transactions longer than `max_len` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus`: The wrapped bus.
+    /// * `max_len`: The largest payload of one transaction; `0` is treated as `1`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(bus: B, max_len: usize) -> Self {
+        Self {
+            bus,
+            max_len: max_len.max(1),
+        }
+    }
+
+    /// Returns the largest payload of one transaction.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Sets the largest payload of one transaction; `0` is treated as `1`.
+    pub fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len.max(1);
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+}
+
+/// Returns the register reached by the auto-increment after `offset` bytes from `reg`.
+fn address_after(reg: u8, offset: usize) -> u8 {
+    if (FIFO_FIRST..=FIFO_LAST).contains(&reg) {
+        let window = usize::from(FIFO_LAST - FIFO_FIRST) + 1;
+        let index = (usize::from(reg - FIFO_FIRST) + offset) % window;
+        FIFO_FIRST.wrapping_add(index as u8)
+    } else {
+        reg.wrapping_add(offset as u8)
+    }
+}
+
+#[bisync]
+impl<B: BusOperation> BusOperation for Chunked<B> {
+    type Error = B::Error;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.read_bytes(rbuf).await
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        let Some((&reg, payload)) = wbuf.split_first() else {
+            return self.bus.write_bytes(wbuf).await;
+        };
+        if payload.len() <= self.max_len {
+            return self.bus.write_bytes(wbuf).await;
+        }
+
+        let mut tmp = [0u8; MAX_WRITE_CHUNK + 1];
+        let mut offset = 0;
+        for chunk in payload.chunks(self.max_len.min(MAX_WRITE_CHUNK)) {
+            let Some((address, data)) = tmp.split_first_mut() else {
+                break;
+            };
+            *address = address_after(reg, offset);
+            if let Some(data) = data.get_mut(..chunk.len()) {
+                data.copy_from_slice(chunk);
+            }
+            if let Some(frame) = tmp.get(..chunk.len() + 1) {
+                self.bus.write_bytes(frame).await?;
+            }
+            offset += chunk.len();
+        }
+        Ok(())
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        if rbuf.len() <= self.max_len {
+            return self.bus.write_byte_read_bytes(wbuf, rbuf).await;
+        }
+
+        let [reg] = *wbuf;
+        let mut offset = 0;
+        for chunk in rbuf.chunks_mut(self.max_len) {
+            let len = chunk.len();
+            self.bus
+                .write_byte_read_bytes(&[address_after(reg, offset)], chunk)
+                .await?;
+            offset += len;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//! Additional transports implementing `BusOperation`.
+//!
+//! Besides the I2C and SPI buses provided by `st-mems-bus`, the driver can be built with
+//! [`Ilps22qs::from_bus`](super::Ilps22qs::from_bus) on top of the transports in this module.
+//!
+//! Any other transport can be plugged in by implementing [`BusOperation`](super::BusOperation),
+//! re-exported from `st-mems-bus` at the root of each flavor. Only the three raw transfers have
+//! to be provided: `write_byte_read_bytes` receives the register address and must read the
+//! register contents starting at that address, `write_bytes` receives the register address
+//! followed by the data to write, and `read_bytes` reads without addressing. The register
+//! helpers `read_from_register` and `write_to_register` have default implementations built on
+//! them.
+
+pub mod chunked;
+pub mod record;
+#[cfg(feature = "bridge")]
+pub mod sc18im704;
+#[cfg(feature = "sim")]
+pub mod sim;
+pub mod soft_cs;
+pub mod write_then_read;
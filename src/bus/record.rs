@@ -0,0 +1,243 @@
+//! Record-and-replay transports.
+//!
+//! [`Recorder`] wraps a transport and logs every successful transaction into a byte buffer;
+//! [`Replay`] plays such a log back, checking that the driver issues the very same writes and
+//! answering its reads with the recorded bytes. A session captured in the field can thus be
+//! reproduced exactly in host tests.
+//!
+//! Each transaction is logged as a tag byte ([`TAG_WRITE`], [`TAG_READ`] or
+//! [`TAG_WRITE_READ`]), the written register address for [`TAG_WRITE_READ`], the payload
+//! length as a little-endian `u16` and the payload bytes.
+
+use super::super::{BusOperation, bisync};
+
+/// Log tag of a `write_bytes` transaction; the payload holds the written bytes.
+pub const TAG_WRITE: u8 = 0x57;
+/// Log tag of a `read_bytes` transaction; the payload holds the read bytes.
+pub const TAG_READ: u8 = 0x52;
+/// Log tag of a `write_byte_read_bytes` transaction; the payload holds the read bytes.
+pub const TAG_WRITE_READ: u8 = 0x58;
+
+/// Transport logging the traffic of another transport.
+///
+/// Transactions that do not fit in the log anymore are still carried out but not recorded,
+/// see [`Recorder::is_truncated`].
+///
+/// ```ignore
+/// let mut log = [0; 4096];
+/// let mut sensor = Ilps22qs::from_bus(Recorder::new(bus, &mut log));
+/// // ... run the session ...
+/// let len = sensor.bus.recorded().len();
+/// ```
+pub struct Recorder<'a, B> {
+    /// The recorded transport.
+    pub bus: B,
+    log: &'a mut [u8],
+    len: usize,
+    truncated: bool,
+}
+
+impl<'a, B> Recorder<'a, B> {
+    /// Creates a recorder logging the traffic of `bus` into `log`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus`: The transport to record.
+    /// * `log`: The buffer receiving the log.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(bus: B, log: &'a mut [u8]) -> Self {
+        Self {
+            bus,
+            log,
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Returns the recorded log.
+    pub fn recorded(&self) -> &[u8] {
+        self.log.get(..self.len).unwrap_or_default()
+    }
+
+    /// Returns `true` if some transactions did not fit in the log.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Clears the log.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+
+    /// Releases the transport and the log.
+    pub fn into_inner(self) -> (B, &'a mut [u8]) {
+        (self.bus, self.log)
+    }
+
+    fn record(&mut self, tag: u8, address: Option<u8>, payload: &[u8]) {
+        let Ok(len) = u16::try_from(payload.len()) else {
+            self.truncated = true;
+            return;
+        };
+        let header_len = 3 + usize::from(address.is_some());
+        let end = self.len + header_len + payload.len();
+        let Some(entry) = self.log.get_mut(self.len..end) else {
+            self.truncated = true;
+            return;
+        };
+
+        let Some((header, data)) = entry.split_at_mut_checked(header_len) else {
+            return;
+        };
+        let [len_lo, len_hi] = len.to_le_bytes();
+        match (header, address) {
+            ([t, a, l, h], Some(address)) => {
+                (*t, *a, *l, *h) = (tag, address, len_lo, len_hi);
+            }
+            ([t, l, h], None) => {
+                (*t, *l, *h) = (tag, len_lo, len_hi);
+            }
+            _ => {}
+        }
+        data.copy_from_slice(payload);
+        self.len = end;
+    }
+}
+
+#[bisync]
+impl<B: BusOperation> BusOperation for Recorder<'_, B> {
+    type Error = B::Error;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.read_bytes(rbuf).await?;
+        self.record(TAG_READ, None, rbuf);
+        Ok(())
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        self.bus.write_bytes(wbuf).await?;
+        self.record(TAG_WRITE, None, wbuf);
+        Ok(())
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.write_byte_read_bytes(wbuf, rbuf).await?;
+        let [address] = *wbuf;
+        self.record(TAG_WRITE_READ, Some(address), rbuf);
+        Ok(())
+    }
+}
+
+/// Errors reported by [`Replay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The transaction at log offset `offset` differs from the one issued by the driver.
+    Mismatch {
+        /// The offset of the recorded transaction in the log.
+        offset: usize,
+    },
+    /// The log holds no more transactions.
+    Exhausted,
+}
+
+/// Transport playing back a log captured by [`Recorder`].
+///
+/// ```ignore
+/// let mut sensor = Ilps22qs::from_bus(Replay::new(include_bytes!("session.bin")));
+/// ```
+pub struct Replay<'a> {
+    log: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Replay<'a> {
+    /// Creates a transport playing back `log`.
+    ///
+    /// # Arguments
+    ///
+    /// * `log`: The log recorded by a [`Recorder`].
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(log: &'a [u8]) -> Self {
+        Self { log, position: 0 }
+    }
+
+    /// Returns the offset of the next transaction in the log.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns `true` once every recorded transaction has been played back.
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.log.len()
+    }
+
+    /// Consumes the next transaction, which must match `tag`, `address` and the payload length.
+    fn next(&mut self, tag: u8, address: Option<u8>, len: usize) -> Result<&'a [u8], ReplayError> {
+        let offset = self.position;
+        let mismatch = ReplayError::Mismatch { offset };
+        let rest = self.log.get(offset..).unwrap_or_default();
+
+        let (&recorded_tag, rest) = rest.split_first().ok_or(ReplayError::Exhausted)?;
+        if recorded_tag != tag {
+            return Err(mismatch);
+        }
+        let rest = match address {
+            Some(address) => match rest.split_first() {
+                Some((&recorded, rest)) if recorded == address => rest,
+                _ => return Err(mismatch),
+            },
+            None => rest,
+        };
+        let (recorded_len, rest) = match rest {
+            [lo, hi, rest @ ..] => (usize::from(u16::from_le_bytes([*lo, *hi])), rest),
+            _ => return Err(mismatch),
+        };
+        if recorded_len != len {
+            return Err(mismatch);
+        }
+        let payload = rest.get(..len).ok_or(mismatch)?;
+
+        self.position = self.log.len() - rest.len() + len;
+        Ok(payload)
+    }
+}
+
+#[bisync]
+impl BusOperation for Replay<'_> {
+    type Error = ReplayError;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        rbuf.copy_from_slice(self.next(TAG_READ, None, rbuf.len())?);
+        Ok(())
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        let offset = self.position;
+        if self.next(TAG_WRITE, None, wbuf.len())? != wbuf {
+            self.position = offset;
+            return Err(ReplayError::Mismatch { offset });
+        }
+        Ok(())
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let [address] = *wbuf;
+        rbuf.copy_from_slice(self.next(TAG_WRITE_READ, Some(address), rbuf.len())?);
+        Ok(())
+    }
+}
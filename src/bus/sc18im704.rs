@@ -0,0 +1,123 @@
+//! Transport over an SC18IM704-style UART-to-I2C bridge.
+//!
+//! The bridge translates ASCII-framed commands received on its UART into I2C transactions, so
+//! the sensor of an evaluation board can be driven from host-side tooling through a serial port.
+
+use super::super::{BusOperation, Read, SevenBitAddress, Write, bisync};
+
+use embedded_io::ReadExactError;
+
+/// Command starting an I2C transaction (or a repeated start).
+const START: u8 = b'S';
+/// Command ending an I2C transaction.
+const STOP: u8 = b'P';
+/// Command reading an internal register of the bridge.
+const READ_REGISTER: u8 = b'R';
+/// Bridge register holding the status of the last I2C transaction.
+const I2C_STAT: u8 = 0x0A;
+/// `I2C_STAT` value reporting a successful transaction.
+const I2C_OK: u8 = 0xF0;
+/// Largest number of bytes transferred by a single bridge command.
+const MAX_LEN: usize = 255;
+
+/// Errors reported by [`Sc18im704`].
+#[derive(Debug)]
+pub enum BridgeError<E> {
+    /// The UART reported an error.
+    Io(E),
+    /// The UART stream ended before the expected number of bytes was received.
+    UnexpectedEof,
+    /// The I2C transaction failed; the value of the bridge `I2C_STAT` register is attached.
+    I2c(u8),
+    /// The transfer exceeds the 255 bytes a single bridge command can carry.
+    TooLong,
+}
+
+/// UART-to-I2C bridge transport.
+///
+/// After a write, the bridge status is read back so NACKs are reported as `BridgeError::I2c`.
+/// A bridge does not answer a failed read, so `uart` should be configured with a read timeout.
+pub struct Sc18im704<U> {
+    /// The UART connected to the bridge.
+    pub uart: U,
+    /// The I2C address of the sensor behind the bridge.
+    pub address: SevenBitAddress,
+}
+
+impl<U> Sc18im704<U> {
+    /// Creates a bridge transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `uart`: The UART connected to the bridge.
+    /// * `address`: The I2C address of the sensor behind the bridge.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(uart: U, address: SevenBitAddress) -> Self {
+        Self { uart, address }
+    }
+}
+
+#[bisync]
+impl<U: Read + Write> Sc18im704<U> {
+    async fn send(&mut self, bytes: &[&[u8]]) -> Result<(), BridgeError<U::Error>> {
+        for part in bytes {
+            self.uart.write_all(part).await.map_err(BridgeError::Io)?;
+        }
+        self.uart.flush().await.map_err(BridgeError::Io)
+    }
+
+    async fn receive(&mut self, buf: &mut [u8]) -> Result<(), BridgeError<U::Error>> {
+        self.uart.read_exact(buf).await.map_err(|err| match err {
+            ReadExactError::UnexpectedEof => BridgeError::UnexpectedEof,
+            ReadExactError::Other(err) => BridgeError::Io(err),
+        })
+    }
+
+    async fn check_status(&mut self) -> Result<(), BridgeError<U::Error>> {
+        self.send(&[&[READ_REGISTER, I2C_STAT, STOP]]).await?;
+        let mut status = [0];
+        self.receive(&mut status).await?;
+        match status {
+            [I2C_OK] => Ok(()),
+            [status] => Err(BridgeError::I2c(status)),
+        }
+    }
+
+    fn header(&self, read: bool, len: usize) -> Result<[u8; 3], BridgeError<U::Error>> {
+        if len > MAX_LEN {
+            return Err(BridgeError::TooLong);
+        }
+        Ok([START, (self.address << 1) | read as u8, len as u8])
+    }
+}
+
+#[bisync]
+impl<U: Read + Write> BusOperation for Sc18im704<U> {
+    type Error = BridgeError<U::Error>;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        let read = self.header(true, rbuf.len())?;
+        self.send(&[&read, &[STOP]]).await?;
+        self.receive(rbuf).await
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        let write = self.header(false, wbuf.len())?;
+        self.send(&[&write, wbuf, &[STOP]]).await?;
+        self.check_status().await
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let write = self.header(false, wbuf.len())?;
+        let read = self.header(true, rbuf.len())?;
+        self.send(&[&write, wbuf, &read, &[STOP]]).await?;
+        self.receive(rbuf).await
+    }
+}
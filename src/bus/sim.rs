@@ -0,0 +1,176 @@
+//! Simulated transport playing back a pressure profile.
+
+use super::super::{
+    BusOperation, TEMPERATURE_SENSITIVITY_LSB_PER_DEG_C, bisync,
+    prelude::{Fs, Odr},
+};
+
+use crate::profile::Profile;
+use core::convert::Infallible;
+
+const WHO_AM_I: usize = 0x0F;
+const CTRL_REG1: usize = 0x10;
+const CTRL_REG2: usize = 0x11;
+const CTRL_REG3: usize = 0x12;
+const FIFO_WTM: usize = 0x15;
+const FIFO_STATUS1: usize = 0x25;
+const STATUS: usize = 0x27;
+const PRESS_OUT_XL: usize = 0x28;
+const TEMP_OUT_L: usize = 0x2B;
+const FIFO_DATA_OUT_PRESS_XL: usize = 0x78;
+
+/// Transport emulating the device register map.
+///
+/// The `Simulator` stores the written configuration like the device does and answers every
+/// pressure read, from the output registers or from the FIFO, with a new sample of the
+/// [`Profile`]. The playback time advances by one ODR period per sample; in one-shot mode it
+/// only advances through [`Simulator::advance_s`]. The FIFO level reads back as the configured
+/// watermark, so watermark-driven drains always find a full batch.
+///
+/// ```ignore
+/// let bus = Simulator::new(Ramp::new(1013.25, -0.01));
+/// let mut sensor = Ilps22qs::from_bus(bus);
+/// ```
+pub struct Simulator<P> {
+    /// The pressure profile played back.
+    pub profile: P,
+    /// The temperature reported by the output registers, in degrees Celsius (°C).
+    pub temperature_deg_c: f32,
+    regs: [u8; 0x80],
+    address: usize,
+    time_s: f32,
+}
+
+impl<P: Profile> Simulator<P> {
+    /// Creates a simulated device in its power-on state, at 25 °C.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile`: The pressure profile to play back.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(profile: P) -> Self {
+        let mut regs = [0; 0x80];
+        if let Some(reg) = regs.get_mut(WHO_AM_I) {
+            *reg = 0xB4;
+        }
+        if let Some(reg) = regs.get_mut(CTRL_REG3) {
+            *reg = 0x01;
+        }
+        if let Some(reg) = regs.get_mut(STATUS) {
+            *reg = 0x03;
+        }
+        Self {
+            profile,
+            temperature_deg_c: 25.0,
+            regs,
+            address: 0,
+            time_s: 0.0,
+        }
+    }
+
+    /// Returns the playback time, in seconds.
+    pub fn time_s(&self) -> f32 {
+        self.time_s
+    }
+
+    /// Advances the playback time by `dt_s` seconds.
+    pub fn advance_s(&mut self, dt_s: f32) {
+        self.time_s += dt_s;
+    }
+
+    fn reg(&self, address: usize) -> u8 {
+        self.regs.get(address).copied().unwrap_or(0)
+    }
+
+    /// Generates a sample of the profile into the three registers starting at `address`.
+    fn convert(&mut self, address: usize) {
+        let fs = if self.reg(CTRL_REG2) & 0x40 != 0 {
+            Fs::_4060hpa
+        } else {
+            Fs::_1260hpa
+        };
+        let lsb_per_hpa = fs.sensitivity().lsb_per_hpa() as f32;
+        let raw = (self.profile.pressure_hpa(self.time_s) * lsb_per_hpa) as i32;
+        let temp = (self.temperature_deg_c * TEMPERATURE_SENSITIVITY_LSB_PER_DEG_C as f32) as i16;
+
+        if let Some(out) = self.regs.get_mut(address..address + 3) {
+            out.copy_from_slice(&raw.to_le_bytes()[..3]);
+        }
+        if address == PRESS_OUT_XL {
+            if let Some(out) = self.regs.get_mut(TEMP_OUT_L..TEMP_OUT_L + 2) {
+                out.copy_from_slice(&temp.to_le_bytes());
+            }
+        }
+
+        let hz = Odr::try_from((self.reg(CTRL_REG1) >> 3) & 0x0F)
+            .unwrap_or_default()
+            .hz();
+        if hz > 0.0 {
+            self.time_s += 1.0 / hz;
+        }
+    }
+
+    fn read(&mut self, rbuf: &mut [u8]) {
+        if self.address == PRESS_OUT_XL || self.address == FIFO_DATA_OUT_PRESS_XL {
+            self.convert(self.address);
+        }
+        if self.address == FIFO_STATUS1 {
+            let level = self.reg(FIFO_WTM);
+            if let Some(reg) = self.regs.get_mut(FIFO_STATUS1) {
+                *reg = level;
+            }
+        }
+        for byte in rbuf.iter_mut() {
+            *byte = self.reg(self.address);
+            self.address += 1;
+        }
+    }
+
+    fn write(&mut self, wbuf: &[u8]) {
+        let Some((&address, data)) = wbuf.split_first() else {
+            return;
+        };
+        self.address = usize::from(address);
+        for &byte in data {
+            let byte = match self.address {
+                WHO_AM_I | STATUS => self.reg(self.address),
+                // BOOT and SWRESET complete immediately
+                CTRL_REG2 => byte & !0x84,
+                _ => byte,
+            };
+            if let Some(reg) = self.regs.get_mut(self.address) {
+                *reg = byte;
+            }
+            self.address += 1;
+        }
+    }
+}
+
+#[bisync]
+impl<P: Profile> BusOperation for Simulator<P> {
+    type Error = Infallible;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.read(rbuf);
+        Ok(())
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        self.write(wbuf);
+        Ok(())
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let [address] = *wbuf;
+        self.address = usize::from(address);
+        self.read(rbuf);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! SPI transport with a software-driven chip select.
+
+use super::super::{BusOperation, OutputPin, RawSpiBus, bisync};
+
+/// Errors reported by [`SoftCsSpi`].
+#[derive(Debug)]
+pub enum SoftCsError<S, P> {
+    /// The SPI bus reported an error.
+    Spi(S),
+    /// The chip select pin reported an error.
+    Pin(P),
+}
+
+/// SPI transport driving the chip select through a GPIO.
+///
+/// The chip select is asserted before the register address is clocked out and released only
+/// after the bus is flushed, so multi-byte reads relying on the register address auto-increment
+/// are carried out in a single frame. The chip select is released even if the transfer fails.
+pub struct SoftCsSpi<S, P> {
+    /// The SPI bus.
+    pub spi: S,
+    /// The chip select pin, active low.
+    pub cs: P,
+}
+
+impl<S, P> SoftCsSpi<S, P> {
+    /// Creates a software chip-select transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `spi`: The SPI bus.
+    /// * `cs`: The chip select pin, initialized high.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(spi: S, cs: P) -> Self {
+        Self { spi, cs }
+    }
+}
+
+#[bisync]
+impl<S: RawSpiBus, P: OutputPin> SoftCsSpi<S, P> {
+    async fn exchange(
+        &mut self,
+        wbuf: &[u8],
+        rbuf: &mut [u8],
+    ) -> Result<(), SoftCsError<S::Error, P::Error>> {
+        self.cs.set_low().map_err(SoftCsError::Pin)?;
+        let transfer = self.transfer(wbuf, rbuf).await.map_err(SoftCsError::Spi);
+        let release = self.cs.set_high().map_err(SoftCsError::Pin);
+        transfer.and(release)
+    }
+
+    async fn transfer(&mut self, wbuf: &[u8], rbuf: &mut [u8]) -> Result<(), S::Error> {
+        if !wbuf.is_empty() {
+            self.spi.write(wbuf).await?;
+        }
+        if !rbuf.is_empty() {
+            self.spi.read(rbuf).await?;
+        }
+        self.spi.flush().await
+    }
+}
+
+#[bisync]
+impl<S: RawSpiBus, P: OutputPin> BusOperation for SoftCsSpi<S, P> {
+    type Error = SoftCsError<S::Error, P::Error>;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.exchange(&[], rbuf).await
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        self.exchange(wbuf, &mut []).await
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let [reg] = *wbuf;
+        self.exchange(&[reg | 0x80], rbuf).await
+    }
+}
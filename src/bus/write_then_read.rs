@@ -0,0 +1,84 @@
+//! Transport replacing combined write-read transactions with a write followed by a read.
+
+use super::super::{BusOperation, bisync};
+
+/// Represents how [`WriteThenRead`] addresses the register to read.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ReadMode {
+    /// Forwards the combined write-read (repeated start) to the wrapped bus.
+    #[default]
+    Combined,
+    /// Writes the register address, then reads the data in a separate transaction.
+    Separate,
+}
+
+/// Transport for buses unable to issue a repeated start.
+///
+/// Bit-banged and bridge-based I2C masters often cannot perform a combined `write_read`. In
+/// [`ReadMode::Separate`] every register read is issued as a write of the register address,
+/// terminated by a stop condition, followed by a read. The device keeps the address pointer
+/// between the two transactions, so auto-increment reads are unaffected. The separate mode is
+/// meant for I2C only: on SPI the chip select is released between the two transactions.
+///
+/// ```ignore
+/// let bus = WriteThenRead::new(I2cBus::new(i2c, I2CAddress::SD0High as SevenBitAddress), ReadMode::Separate);
+/// let mut sensor = Ilps22qs::from_bus(bus);
+/// ```
+pub struct WriteThenRead<B> {
+    /// The wrapped bus.
+    pub bus: B,
+    mode: ReadMode,
+}
+
+impl<B> WriteThenRead<B> {
+    /// Creates a transport issuing register reads according to `mode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus`: The wrapped bus.
+    /// * `mode`: How the register address is written before a read.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`
+    pub fn new(bus: B, mode: ReadMode) -> Self {
+        Self { bus, mode }
+    }
+
+    /// Returns how register reads are issued.
+    pub fn mode(&self) -> ReadMode {
+        self.mode
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+}
+
+#[bisync]
+impl<B: BusOperation> BusOperation for WriteThenRead<B> {
+    type Error = B::Error;
+
+    async fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.bus.read_bytes(rbuf).await
+    }
+
+    async fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        self.bus.write_bytes(wbuf).await
+    }
+
+    async fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        match self.mode {
+            ReadMode::Combined => self.bus.write_byte_read_bytes(wbuf, rbuf).await,
+            ReadMode::Separate => {
+                self.bus.write_bytes(wbuf).await?;
+                self.bus.read_bytes(rbuf).await
+            }
+        }
+    }
+}
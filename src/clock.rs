@@ -0,0 +1,75 @@
+//! Time sources used to stamp samples and events.
+//!
+//! The driver does not own a timer: the methods stamping samples or events borrow a
+//! [`Timestamp`] for the duration of the call, so the same code runs on embassy-time, an RTIC
+//! monotonic or a bare cycle counter. Any `FnMut() -> u64` closure returning microseconds is a
+//! `Timestamp`, e.g. `|| Mono::now().duration_since_epoch().to_micros()` with an RTIC
+//! monotonic; [`CycleCounter`] turns a free-running 32-bit cycle counter such as the Cortex-M
+//! DWT `CYCCNT` into one.
+
+/// A source of the current time, in microseconds.
+pub trait Timestamp {
+    /// Returns the current time, in microseconds since an arbitrary epoch.
+    ///
+    /// The values must not decrease between two calls.
+    fn now_us(&mut self) -> u64;
+}
+
+impl<F: FnMut() -> u64> Timestamp for F {
+    fn now_us(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// Time source built on a free-running 32-bit cycle counter.
+///
+/// The counter wrap-around is tracked to extend it to 64 bits, so `now_us` must be called at
+/// least once per wrap period (about 53 s at 80 MHz).
+///
+/// ```ignore
+/// let mut clock = CycleCounter::new(|| DWT::cycle_count(), 84_000_000);
+/// let (timestamp_us, data) = sensor.data_get_clocked(&mut clock)?;
+/// ```
+pub struct CycleCounter<F> {
+    read: F,
+    hz: u32,
+    last: u32,
+    cycles: u64,
+}
+
+impl<F: FnMut() -> u32> CycleCounter<F> {
+    /// Creates the time source reading the counter with `read`, which runs at `hz`.
+    ///
+    /// The time starts at zero on creation. A frequency of zero is handled as 1 Hz.
+    pub fn new(mut read: F, hz: u32) -> Self {
+        let last = read();
+        Self {
+            read,
+            hz: hz.max(1),
+            last,
+            cycles: 0,
+        }
+    }
+}
+
+impl<F: FnMut() -> u32> Timestamp for CycleCounter<F> {
+    fn now_us(&mut self) -> u64 {
+        let now = (self.read)();
+        self.cycles += u64::from(now.wrapping_sub(self.last));
+        self.last = now;
+        // 128-bit intermediate: the product overflows `u64` after a few hours at high frequencies
+        (u128::from(self.cycles) * 1_000_000 / u128::from(self.hz)) as u64
+    }
+}
+
+/// Time source reading `embassy_time::Instant::now`.
+#[cfg(feature = "embassy")]
+#[derive(Clone, Copy, Default)]
+pub struct EmbassyClock;
+
+#[cfg(feature = "embassy")]
+impl Timestamp for EmbassyClock {
+    fn now_us(&mut self) -> u64 {
+        embassy_time::Instant::now().as_micros()
+    }
+}
@@ -0,0 +1,344 @@
+//! Free functions mirroring the API of ST's C driver.
+//!
+//! Each function is named after its `ilps22qs_reg.c` counterpart and takes the driver in place
+//! of the `stmdev_ctx_t` context, so existing C application code can be ported line by line:
+//!
+//! ```c
+//! ilps22qs_md_t md;
+//! ilps22qs_mode_get(&dev_ctx, &md);
+//! md.odr = ILPS22QS_25Hz;
+//! ilps22qs_mode_set(&dev_ctx, &md);
+//! ilps22qs_data_get(&dev_ctx, &md, &data);
+//! ```
+//!
+//! ```ignore
+//! let mut md = Md::default();
+//! ilps22qs_mode_get(&mut sensor, &mut md)?;
+//! md.odr = Odr::_25hz;
+//! ilps22qs_mode_set(&mut sensor, &md)?;
+//! ilps22qs_data_get(&mut sensor, &md, &mut data)?;
+//! ```
+//!
+//! Getters fill an output parameter as in C, and the `int32_t` status code becomes a `Result`.
+//! The only signature change is [`ilps22qs_init_set`], which takes the delay used while waiting
+//! for the boot and reset procedures. New code should call the [`Ilps22qs`] methods directly.
+
+use super::{BusOperation, DelayNs, Error, Ilps22qs, bisync, prelude::*};
+
+/// Mirrors `ilps22qs_from_fs1260_to_hPa`; see [`from_fs1260_to_hpa`](super::from_fs1260_to_hpa).
+#[cfg(feature = "float")]
+#[allow(non_snake_case)]
+pub fn ilps22qs_from_fs1260_to_hPa(lsb: i32) -> f32 {
+    super::from_fs1260_to_hpa(lsb)
+}
+
+/// Mirrors `ilps22qs_from_fs4000_to_hPa`; see [`from_fs4000_to_hpa`](super::from_fs4000_to_hpa).
+#[cfg(feature = "float")]
+#[allow(non_snake_case)]
+pub fn ilps22qs_from_fs4000_to_hPa(lsb: i32) -> f32 {
+    super::from_fs4000_to_hpa(lsb)
+}
+
+/// Mirrors `ilps22qs_from_lsb_to_celsius`; see [`from_lsb_to_celsius`](super::from_lsb_to_celsius).
+#[cfg(feature = "float")]
+pub fn ilps22qs_from_lsb_to_celsius(lsb: i16) -> f32 {
+    super::from_lsb_to_celsius(lsb)
+}
+
+/// Mirrors `ilps22qs_from_lsb_to_mv`; see [`from_lsb_to_mv`](super::from_lsb_to_mv).
+#[cfg(feature = "qvar")]
+pub fn ilps22qs_from_lsb_to_mv(lsb: i32) -> f32 {
+    super::from_lsb_to_mv(lsb)
+}
+
+/// Mirrors `ilps22qs_id_get`; see [`Ilps22qs::id_get`].
+#[bisync]
+pub async fn ilps22qs_id_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut WhoAmI,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.id_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_bus_mode_set`; see [`Ilps22qs::bus_mode_set`].
+#[bisync]
+pub async fn ilps22qs_bus_mode_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &BusMode,
+) -> Result<(), Error<B::Error>> {
+    ctx.bus_mode_set(*val).await
+}
+
+/// Mirrors `ilps22qs_bus_mode_get`; see [`Ilps22qs::bus_mode_get`].
+#[bisync]
+pub async fn ilps22qs_bus_mode_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut BusMode,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.bus_mode_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_init_set`; see [`Ilps22qs::init_set`].
+///
+/// Unlike the C function, the delay used to wait for the boot and reset procedures is passed
+/// explicitly.
+#[bisync]
+pub async fn ilps22qs_init_set<B: BusOperation, D: DelayNs>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: Init,
+    delay: &mut D,
+) -> Result<(), Error<B::Error>> {
+    ctx.init_set(val, delay).await
+}
+
+/// Mirrors `ilps22qs_status_get`; see [`Ilps22qs::status_get`].
+#[bisync]
+pub async fn ilps22qs_status_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut Stat,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.status_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_pin_conf_set`; see [`Ilps22qs::pin_conf_set`].
+#[bisync]
+pub async fn ilps22qs_pin_conf_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &PinConf,
+) -> Result<(), Error<B::Error>> {
+    ctx.pin_conf_set(val).await
+}
+
+/// Mirrors `ilps22qs_pin_conf_get`; see [`Ilps22qs::pin_conf_get`].
+#[bisync]
+pub async fn ilps22qs_pin_conf_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut PinConf,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.pin_conf_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_all_sources_get`; see [`Ilps22qs::all_sources_get`].
+#[bisync]
+pub async fn ilps22qs_all_sources_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut AllSources,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.all_sources_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_mode_set`; see [`Ilps22qs::mode_set`].
+#[bisync]
+pub async fn ilps22qs_mode_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &Md,
+) -> Result<(), Error<B::Error>> {
+    ctx.mode_set(val).await
+}
+
+/// Mirrors `ilps22qs_mode_get`; see [`Ilps22qs::mode_get`].
+#[bisync]
+pub async fn ilps22qs_mode_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut Md,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.mode_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_trigger_sw`; see [`Ilps22qs::trigger_sw`].
+///
+/// As in C, the conversion is started when `md` rather than the parameters kept by the driver
+/// selects `Odr::OneShot`.
+#[bisync]
+pub async fn ilps22qs_trigger_sw<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    md: &Md,
+) -> Result<(), Error<B::Error>> {
+    if md.odr == Odr::OneShot {
+        ctx.oneshot_start().await?;
+    }
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_ah_qvar_en_set`; see [`Ilps22qs::ah_qvar_en_set`].
+#[cfg(feature = "qvar")]
+#[bisync]
+pub async fn ilps22qs_ah_qvar_en_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: bool,
+) -> Result<(), Error<B::Error>> {
+    ctx.ah_qvar_en_set(val).await
+}
+
+/// Mirrors `ilps22qs_ah_qvar_en_get`; see [`Ilps22qs::ah_qvar_en_get`].
+#[cfg(feature = "qvar")]
+#[bisync]
+pub async fn ilps22qs_ah_qvar_en_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut bool,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.ah_qvar_en_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_data_get`; see [`Ilps22qs::data_get`].
+///
+/// As in C, the samples are converted with `md` rather than with the parameters kept by the
+/// driver.
+#[cfg(feature = "float")]
+#[bisync]
+pub async fn ilps22qs_data_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    md: &Md,
+    data: &mut Data,
+) -> Result<(), Error<B::Error>> {
+    *data = ctx.data_get_with(md).await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_ah_qvar_data_get`; see [`Ilps22qs::ah_qvar_data_get`].
+#[cfg(feature = "qvar")]
+#[bisync]
+pub async fn ilps22qs_ah_qvar_data_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    data: &mut AhQvarData,
+) -> Result<(), Error<B::Error>> {
+    *data = ctx.ah_qvar_data_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_fifo_mode_set`; see [`Ilps22qs::fifo_mode_set`].
+#[bisync]
+pub async fn ilps22qs_fifo_mode_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &FifoMd,
+) -> Result<(), Error<B::Error>> {
+    ctx.fifo_mode_set(val).await
+}
+
+/// Mirrors `ilps22qs_fifo_mode_get`; see [`Ilps22qs::fifo_mode_get`].
+#[bisync]
+pub async fn ilps22qs_fifo_mode_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut FifoMd,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.fifo_mode_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_fifo_level_get`; see [`Ilps22qs::fifo_level_get`].
+#[bisync]
+pub async fn ilps22qs_fifo_level_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut u8,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.fifo_level_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_fifo_data_get`; see [`Ilps22qs::fifo_data_get`].
+///
+/// As in C, the samples are converted with `md`, and `data` must hold at least `samp` samples;
+/// otherwise `Error::BufferTooSmall` is returned.
+#[cfg(feature = "float")]
+#[bisync]
+pub async fn ilps22qs_fifo_data_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    samp: u8,
+    md: &Md,
+    data: &mut [FifoData],
+) -> Result<(), Error<B::Error>> {
+    ctx.fifo_data_get_with(samp, md, data).await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_interrupt_mode_set`; see [`Ilps22qs::interrupt_mode_set`].
+#[bisync]
+pub async fn ilps22qs_interrupt_mode_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    int_latched: bool,
+) -> Result<(), Error<B::Error>> {
+    ctx.interrupt_mode_set(int_latched).await
+}
+
+/// Mirrors `ilps22qs_interrupt_mode_get`; see [`Ilps22qs::interrupt_mode_get`].
+#[bisync]
+pub async fn ilps22qs_interrupt_mode_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    int_latched: &mut bool,
+) -> Result<(), Error<B::Error>> {
+    *int_latched = ctx.interrupt_mode_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_ah_qvar_disable`; see [`Ilps22qs::ah_qvar_disable`].
+#[cfg(feature = "qvar")]
+#[bisync]
+pub async fn ilps22qs_ah_qvar_disable<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+) -> Result<(), Error<B::Error>> {
+    ctx.ah_qvar_disable().await
+}
+
+/// Mirrors `ilps22qs_int_on_threshold_mode_set`; see [`Ilps22qs::int_on_threshold_mode_set`].
+#[bisync]
+pub async fn ilps22qs_int_on_threshold_mode_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &IntThMd,
+) -> Result<(), Error<B::Error>> {
+    ctx.int_on_threshold_mode_set(val).await
+}
+
+/// Mirrors `ilps22qs_int_on_threshold_mode_get`; see [`Ilps22qs::int_on_threshold_mode_get`].
+#[bisync]
+pub async fn ilps22qs_int_on_threshold_mode_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut IntThMd,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.int_on_threshold_mode_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_reference_mode_set`; see [`Ilps22qs::reference_mode_set`].
+#[bisync]
+pub async fn ilps22qs_reference_mode_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &RefMd,
+) -> Result<(), Error<B::Error>> {
+    ctx.reference_mode_set(val).await
+}
+
+/// Mirrors `ilps22qs_reference_mode_get`; see [`Ilps22qs::reference_mode_get`].
+#[bisync]
+pub async fn ilps22qs_reference_mode_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut RefMd,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.reference_mode_get().await?;
+    Ok(())
+}
+
+/// Mirrors `ilps22qs_opc_set`; see [`Ilps22qs::opc_set`].
+#[bisync]
+pub async fn ilps22qs_opc_set<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: i16,
+) -> Result<(), Error<B::Error>> {
+    ctx.opc_set(val).await
+}
+
+/// Mirrors `ilps22qs_opc_get`; see [`Ilps22qs::opc_get`].
+#[bisync]
+pub async fn ilps22qs_opc_get<B: BusOperation>(
+    ctx: &mut Ilps22qs<B, OnState>,
+    val: &mut i16,
+) -> Result<(), Error<B::Error>> {
+    *val = ctx.opc_get().await?;
+    Ok(())
+}
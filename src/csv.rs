@@ -0,0 +1,69 @@
+//! CSV serialization of sensor samples over `embedded-io`.
+//!
+//! The helpers write one line per sample into any `embedded_io::Write` implementation (UART,
+//! USB CDC, ...), replacing the `core::fmt::Write` boilerplate otherwise needed to stream data
+//! to a host.
+//!
+//! ```ignore
+//! csv::write_data_header(&mut uart)?;
+//! loop {
+//!     let data = sensor.data_get()?;
+//!     csv::write_data(&mut uart, &data)?;
+//! }
+//! ```
+
+use super::prelude::*;
+
+use embedded_io::{Write, WriteFmtError};
+
+/// Column names of the lines written by [`write_data`].
+pub const DATA_HEADER: &str =
+    "pressure_raw,pressure_hpa,temperature_raw,temperature_deg_c,ah_qvar_lsb";
+
+/// Column names of the lines written by [`write_fifo_data`].
+pub const FIFO_DATA_HEADER: &str = "raw,hpa,lsb,timestamp_us";
+
+/// Writes the [`DATA_HEADER`] line.
+///
+/// # Errors
+///
+/// * `WriteFmtError::Other(E)`: Occurs if the writer fails.
+pub fn write_data_header<W: Write>(writer: &mut W) -> Result<(), WriteFmtError<W::Error>> {
+    writer.write_fmt(format_args!("{DATA_HEADER}\r\n"))
+}
+
+/// Writes a `Data` sample as a CSV line.
+///
+/// # Errors
+///
+/// * `WriteFmtError::Other(E)`: Occurs if the writer fails.
+pub fn write_data<W: Write>(writer: &mut W, data: &Data) -> Result<(), WriteFmtError<W::Error>> {
+    writer.write_fmt(format_args!(
+        "{},{:.4},{},{:.2},{}\r\n",
+        data.pressure.raw, data.pressure.hpa, data.heat.raw, data.heat.deg_c, data.ah_qvar.lsb
+    ))
+}
+
+/// Writes the [`FIFO_DATA_HEADER`] line.
+///
+/// # Errors
+///
+/// * `WriteFmtError::Other(E)`: Occurs if the writer fails.
+pub fn write_fifo_data_header<W: Write>(writer: &mut W) -> Result<(), WriteFmtError<W::Error>> {
+    writer.write_fmt(format_args!("{FIFO_DATA_HEADER}\r\n"))
+}
+
+/// Writes a `FifoData` sample as a CSV line.
+///
+/// # Errors
+///
+/// * `WriteFmtError::Other(E)`: Occurs if the writer fails.
+pub fn write_fifo_data<W: Write>(
+    writer: &mut W,
+    data: &FifoData,
+) -> Result<(), WriteFmtError<W::Error>> {
+    writer.write_fmt(format_args!(
+        "{},{:.4},{},{}\r\n",
+        data.raw, data.hpa, data.lsb, data.timestamp_us
+    ))
+}
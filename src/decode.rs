@@ -0,0 +1,139 @@
+//! Pure decoding of raw output frames.
+//!
+//! These functions turn the bytes of the output and FIFO registers into [`Data`] and
+//! [`FifoData`] without any bus access, so they can be fuzzed or property-tested on the host and
+//! reused by host-side tools receiving raw frames, e.g. over telemetry. The driver uses them
+//! internally, then applies the software compensation, calibration and filtering stages, which
+//! are not part of the decoding.
+
+use super::prelude::*;
+#[cfg(feature = "float")]
+use super::{from_lsb_to_celsius, from_lsb_to_hpa};
+
+/// Assembles the bytes of a pressure output into the raw value.
+///
+/// # Parameters
+///
+/// * `bytes`: The XL, L and H bytes of PRESS_OUT or FIFO_DATA_OUT_PRESS, in register order.
+///
+/// # Returns
+///
+/// * `i32`: The raw value, left-aligned on 32 bits as returned by the driver.
+pub const fn raw_pressure_from_bytes(bytes: [u8; 3]) -> i32 {
+    let [xl, l, h] = bytes;
+    i32::from_le_bytes([0, xl, l, h])
+}
+
+/// Returns `true` if `raw` is an AH/QVAR sample of an interleaved stream.
+///
+/// In interleaved mode the device flags AH/QVAR samples with bit 0 of PRESS_OUT_XL (and of
+/// FIFO_DATA_OUT_PRESS_XL). ST's `ilps22qs_reg.c` tests it as `buff[0] & 0x1`, before
+/// left-aligning the value, so in the raw value returned by the driver the flag is bit 8; bit 0
+/// of the raw value is always clear.
+///
+/// # Parameters
+///
+/// * `raw`: The raw pressure output value.
+/// * `md`: The sensor conversion parameters.
+pub const fn is_ah_qvar_sample(raw: i32, md: &Md) -> bool {
+    md.interleaved_mode && (raw & 0x100) != 0
+}
+
+/// Converts a raw pressure output value into hPa.
+///
+/// # Parameters
+///
+/// * `raw`: The raw pressure output value.
+/// * `fs`: The full scale in use.
+#[cfg(feature = "float")]
+pub fn hpa_from_raw(raw: i32, fs: Fs) -> f32 {
+    from_lsb_to_hpa(fs, raw)
+}
+
+/// Decodes a raw FIFO sample.
+///
+/// # Parameters
+///
+/// * `raw`: The raw FIFO output value.
+/// * `md`: The sensor conversion parameters.
+///
+/// # Returns
+///
+/// * `FifoData`: The decoded sample, with a zero timestamp.
+#[cfg(feature = "float")]
+pub fn fifo_data_from_raw(raw: i32, md: &Md) -> FifoData {
+    if is_ah_qvar_sample(raw, md) {
+        FifoData {
+            raw,
+            lsb: raw >> 8,
+            ..Default::default()
+        }
+    } else {
+        FifoData {
+            raw,
+            hpa: hpa_from_raw(raw, md.fs),
+            ..Default::default()
+        }
+    }
+}
+
+/// Decodes the bytes of a FIFO sample.
+///
+/// # Parameters
+///
+/// * `bytes`: The bytes of FIFO_DATA_OUT_PRESS_XL to FIFO_DATA_OUT_PRESS_H.
+/// * `md`: The sensor conversion parameters.
+///
+/// # Returns
+///
+/// * `FifoData`: The decoded sample, with a zero timestamp.
+#[cfg(feature = "float")]
+pub fn fifo_data_from_bytes(bytes: [u8; 3], md: &Md) -> FifoData {
+    fifo_data_from_raw(raw_pressure_from_bytes(bytes), md)
+}
+
+/// Decodes the bytes of the output registers.
+///
+/// # Parameters
+///
+/// * `bytes`: The bytes of PRESS_OUT_XL to TEMP_OUT_H.
+/// * `md`: The sensor conversion parameters.
+///
+/// # Returns
+///
+/// * `Data`: The decoded pressure or AH/QVAR sample and temperature.
+#[cfg(feature = "float")]
+pub fn data_from_bytes(bytes: [u8; 5], md: &Md) -> Data {
+    let [xl, l, h, t_l, t_h] = bytes;
+    data_from_raw(
+        raw_pressure_from_bytes([xl, l, h]),
+        i16::from_le_bytes([t_l, t_h]),
+        md,
+    )
+}
+
+/// Decodes raw output values.
+///
+/// # Parameters
+///
+/// * `pressure`: The raw pressure output value.
+/// * `temperature`: The raw temperature output value.
+/// * `md`: The sensor conversion parameters.
+///
+/// # Returns
+///
+/// * `Data`: The decoded pressure or AH/QVAR sample and temperature.
+#[cfg(feature = "float")]
+pub fn data_from_raw(pressure: i32, temperature: i16, md: &Md) -> Data {
+    let mut data = Data::default();
+    data.pressure.raw = pressure;
+    data.heat.raw = temperature;
+    data.heat.deg_c = from_lsb_to_celsius(temperature);
+
+    if is_ah_qvar_sample(pressure, md) {
+        data.ah_qvar.lsb = pressure >> 8;
+    } else {
+        data.pressure.hpa = hpa_from_raw(pressure, md.fs);
+    }
+    data
+}
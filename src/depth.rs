@@ -0,0 +1,80 @@
+//! Hydrostatic depth helpers.
+//!
+//! The water-resistant package of the sensor can be used for level and depth sensing: the
+//! pressure above a tared surface reference is converted into the depth of a fluid column.
+
+/// Standard gravity, in m/s².
+pub const STANDARD_GRAVITY: f32 = 9.80665;
+
+/// Density of fresh water at 25 °C, in kg/m³.
+pub const FRESH_WATER_DENSITY: f32 = 997.0;
+
+/// Typical density of sea water, in kg/m³.
+pub const SEA_WATER_DENSITY: f32 = 1025.0;
+
+/// Fluid depth below a tared surface pressure.
+///
+/// The `Depth` helper is zeroed with [`Depth::tare`] at the surface, e.g. with the sensor in
+/// air right above the water, and then reports the depth in meters from the pressure increase.
+/// Until it is tared, no depth is reported.
+///
+/// ```ignore
+/// let mut depth = Depth::new(FRESH_WATER_DENSITY);
+/// depth.tare(sensor.data_get().await?.pressure.hpa);
+/// let depth_m = depth.depth_m(sensor.data_get().await?.pressure.hpa);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Depth {
+    surface_hpa: Option<f32>,
+    density_kg_m3: f32,
+}
+
+impl Depth {
+    /// Creates a helper for a fluid of density `density_kg_m3`, not tared yet.
+    pub const fn new(density_kg_m3: f32) -> Self {
+        Self {
+            surface_hpa: None,
+            density_kg_m3,
+        }
+    }
+
+    /// Zeros the helper at the surface pressure `hpa`.
+    pub fn tare(&mut self, hpa: f32) {
+        self.surface_hpa = Some(hpa);
+    }
+
+    /// Clears the tared reference.
+    pub fn reset(&mut self) {
+        self.surface_hpa = None;
+    }
+
+    /// Returns `true` once the helper has been tared.
+    pub const fn is_tared(&self) -> bool {
+        self.surface_hpa.is_some()
+    }
+
+    /// Returns the density of the fluid, in kg/m³.
+    pub const fn density_kg_m3(&self) -> f32 {
+        self.density_kg_m3
+    }
+
+    /// Sets the density of the fluid, in kg/m³.
+    pub fn set_density_kg_m3(&mut self, density_kg_m3: f32) {
+        self.density_kg_m3 = density_kg_m3;
+    }
+
+    /// Returns the depth in meters at the pressure `hpa`, or `None` if not tared.
+    ///
+    /// Positive values are below the tared surface.
+    pub fn depth_m(&self, hpa: f32) -> Option<f32> {
+        self.surface_hpa.map(|surface_hpa| {
+            (hpa - surface_hpa) * 100.0 / (self.density_kg_m3 * STANDARD_GRAVITY)
+        })
+    }
+}
+
+impl Default for Depth {
+    fn default() -> Self {
+        Self::new(FRESH_WATER_DENSITY)
+    }
+}
@@ -1,23 +1,43 @@
 use super::{
-    BusOperation, DelayNs, I2c, RegisterOperation, SensorOperation, SevenBitAddress, SpiDevice,
-    bisync, i2c, prelude::*, spi,
+    BusOperation, DelayNs, I2c, OutputPin, RawSpiBus, RegisterOperation, SensorOperation,
+    SevenBitAddress, SpiDevice, bisync, bus::soft_cs::SoftCsSpi, decode, i2c, prelude::*, spi,
 };
 
+#[cfg(any(feature = "float", feature = "heapless"))]
+use crate::clock::Timestamp;
+#[cfg(feature = "float")]
+use crate::filter::{SampleFilter, SpikeFilter};
 use core::fmt::Debug;
 use core::marker::PhantomData;
+use embedded_hal::i2c::{Error as _, ErrorKind};
 
 /// The Ilps22qs generic driver struct.
 #[bisync]
-pub struct Ilps22qs<B, T, S>
+pub struct Ilps22qs<B, S>
 where
     B: BusOperation,
-    T: DelayNs,
     S: SensorState,
 {
     /// The bus driver.
     pub bus: B,
-    /// The timing peripheral.
-    pub tim: T,
+    #[cfg(feature = "float")]
+    temp_comp: Option<TempCompensation>,
+    #[cfg(feature = "float")]
+    user_correction: UserCorrection,
+    #[cfg(feature = "float")]
+    spike_filter: Option<SpikeFilter>,
+    #[cfg(feature = "float")]
+    tare_hpa: Option<f32>,
+    #[cfg(feature = "float")]
+    pub(crate) calibration: Calibration,
+    #[cfg(feature = "qvar")]
+    qvar_sensitivity: f32,
+    pub(crate) md: Md,
+    drv_rdy: bool,
+    recovery: Option<RecoveryPolicy>,
+    bus_errors: u8,
+    seq: u32,
+    fifo_stats: FifoStats,
     _state: PhantomData<S>,
 }
 
@@ -30,20 +50,127 @@ pub enum Error<B> {
     /// An error occurred at the bus level. Any methods that access the I2C/SPI bus to interact with the sensor may return this error if the bus operation fails.
     ///
     /// The generic type B represents the specific error generated by the HAL of the microcontroller in use.
-    Bus(B),
+    /// It is wrapped in a [`BusError`] telling which operation and register failed.
+    Bus(BusError<B>),
     /// An error occured during boot procedure
     Boot,
     /// An error occured during software reset procedure
     SwReset,
-    /// The error return when the fifo sample size is grater than the buffer size
-    FifoSampGraterThanBuff,
+    /// The buffer passed to a FIFO read is smaller than the requested number of samples
+    BufferTooSmall,
+    /// The driver is in use by the other handle returned by `split`
+    Busy,
+    /// The awaited condition was not met within the timeout
+    Timeout,
+    /// The device lost the configuration applied through the driver, e.g. after a brown-out
+    DeviceReset,
+    /// The consecutive bus errors reached the threshold of a `RecoveryAction::Fatal` policy
+    Fatal,
+}
+
+/// Longest time a waiting function polls the device.
+///
+/// A `Timeout` is built from a number of microseconds (`u32`) or, with the `fugit` feature,
+/// from any `fugit` duration, so the waiting functions accept the timebase of the application.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Timeout {
+    us: u32,
+}
+
+impl Timeout {
+    /// Creates a timeout of `us` microseconds.
+    pub const fn from_micros(us: u32) -> Self {
+        Self { us }
+    }
+
+    /// Creates a timeout of `ms` milliseconds, saturating at `u32::MAX` microseconds.
+    pub const fn from_millis(ms: u32) -> Self {
+        Self::from_micros(ms.saturating_mul(1000))
+    }
+
+    /// Returns the timeout in microseconds.
+    pub const fn as_micros(&self) -> u32 {
+        self.us
+    }
+}
+
+impl From<u32> for Timeout {
+    /// Interprets `us` as microseconds.
+    fn from(us: u32) -> Self {
+        Self::from_micros(us)
+    }
+}
+
+#[cfg(feature = "fugit")]
+impl<const NOM: u32, const DENOM: u32> From<fugit::Duration<u32, NOM, DENOM>> for Timeout {
+    fn from(duration: fugit::Duration<u32, NOM, DENOM>) -> Self {
+        Self::from_micros(duration.to_micros())
+    }
+}
+
+#[cfg(feature = "fugit")]
+impl<const NOM: u32, const DENOM: u32> From<fugit::Duration<u64, NOM, DENOM>> for Timeout {
+    /// Saturates at `u32::MAX` microseconds.
+    fn from(duration: fugit::Duration<u64, NOM, DENOM>) -> Self {
+        Self::from_micros(u32::try_from(duration.to_micros()).unwrap_or(u32::MAX))
+    }
+}
+
+/// Kind of a failed bus operation.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[bisync]
+pub enum BusOp {
+    /// A register read.
+    Read,
+    /// A register write.
+    Write,
+}
+
+/// Bus error with the context of the failed operation.
+///
+/// The `Display` implementation produces messages such as
+/// `read of 3 bytes at FIFO_DATA_OUT_PRESS_XL (0x78) failed: Nack`, actionable from field logs.
+#[derive(Debug)]
+#[bisync]
+pub struct BusError<B> {
+    /// The error generated by the HAL of the microcontroller in use.
+    pub source: B,
+    /// The failed operation.
+    pub op: BusOp,
+    /// The address of the first register accessed.
+    pub reg: u8,
+    /// The number of bytes transferred.
+    pub len: usize,
+}
+
+#[bisync]
+impl<B> BusError<B> {
+    /// Returns the register accessed, if the address is a known one.
+    pub fn register(&self) -> Option<Reg> {
+        Reg::try_from(self.reg).ok()
+    }
+}
+
+#[bisync]
+impl<B: Debug> core::fmt::Display for BusError<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let op = match self.op {
+            BusOp::Read => "read",
+            BusOp::Write => "write",
+        };
+        let name = self.register().map_or("unknown register", Reg::name);
+        write!(
+            f,
+            "{op} of {} bytes at {name} (0x{:02X}) failed: {:?}",
+            self.len, self.reg, self.source
+        )
+    }
 }
 
 #[bisync]
-impl<P, T> Ilps22qs<i2c::I2cBus<P>, T, OnState>
+impl<P> Ilps22qs<i2c::I2cBus<P>, OnState>
 where
     P: I2c,
-    T: DelayNs,
 {
     /// Constructor method for using the I2C bus.
     ///
@@ -51,27 +178,61 @@ where
     ///
     /// * `i2c`: The I2C peripheral.
     /// * `address`: The I2C address of the COMPONENT sensor.
-    /// * `tim`: The timer of the COMPONENT sensor.
     ///
     /// # Returns
     ///
     /// * `Self`: Returns an instance of `Ilps22qs`.
-    pub fn new_i2c(i2c: P, address: I2CAddress, tim: T) -> Self {
+    pub fn new_i2c(i2c: P, address: I2CAddress) -> Self {
         // Initialize the I2C bus with the COMPONENT address
-        let bus = i2c::I2cBus::new(i2c, address as SevenBitAddress);
-        Self {
-            bus,
-            tim,
-            _state: PhantomData,
+        Self::from_bus(i2c::I2cBus::new(i2c, address as SevenBitAddress))
+    }
+
+    /// Constructor method for using the I2C bus with an arbitrary 7-bit address.
+    ///
+    /// This is meant for systems where the device is reached at a remapped address, e.g. through
+    /// an LTC4316-style address translator or an I2C gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `i2c`: The I2C peripheral.
+    /// * `address`: The 7-bit I2C address the COMPONENT sensor is reachable at.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`: Returns an instance of `Ilps22qs`.
+    pub fn new_i2c_with_address(i2c: P, address: SevenBitAddress) -> Self {
+        Self::from_bus(i2c::I2cBus::new(i2c, address))
+    }
+
+    /// Checks whether a responsive ILPS22QS answers at an I2C address.
+    ///
+    /// The peripheral is only borrowed and the device is not configured, so this is suited to
+    /// hot-plug and board-variant detection before building the driver, see [`Self::probe`].
+    /// An address not acknowledged is reported as an absent device.
+    ///
+    /// # Arguments
+    ///
+    /// * `i2c`: The I2C peripheral.
+    /// * `address`: The I2C address to probe.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, P::Error>`
+    ///     * `bool`: `true` if the device at `address` returns the ILPS22QS WHO_AM_I value.
+    ///     * `Err`: Returns the error of the peripheral, if other than a missing acknowledge.
+    pub async fn probe_i2c(i2c: &mut P, address: I2CAddress) -> Result<bool, P::Error> {
+        let mut bus = i2c::I2cBus::new(i2c, address as SevenBitAddress);
+        match Ilps22qs::probe(&mut bus).await {
+            Err(e) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => Ok(false),
+            result => result,
         }
     }
 }
 
 #[bisync]
-impl<B, T, S> Ilps22qs<B, T, S>
+impl<B, S> Ilps22qs<B, S>
 where
     B: BusOperation,
-    T: DelayNs,
     S: SensorState,
 {
     /// Create a safe fake buffer to use the sensor as master of the
@@ -80,58 +241,225 @@ where
     /// # Arguments
     ///
     /// * `bus`: The bus that implements BusOperation.
-    /// * `tim`: The timer of the COMPONENT sensor.
     /// * `slave_address`: The I2C address of the slave sensor
     ///
     /// # Returns
     ///
     /// * `Self`: Returns an instance of `Ilps22qs`.
-    pub fn from_bus(bus: B, tim: T) -> Self {
+    pub fn from_bus(bus: B) -> Self {
         Self {
             bus,
-            tim,
+            #[cfg(feature = "float")]
+            temp_comp: None,
+            #[cfg(feature = "float")]
+            user_correction: UserCorrection::default(),
+            #[cfg(feature = "float")]
+            spike_filter: None,
+            #[cfg(feature = "float")]
+            tare_hpa: None,
+            #[cfg(feature = "float")]
+            calibration: Calibration::DEFAULT,
+            #[cfg(feature = "qvar")]
+            qvar_sensitivity: QVAR_SENSITIVITY_LSB_PER_MV,
+            md: Md::default(),
+            drv_rdy: false,
+            recovery: None,
+            bus_errors: 0,
+            seq: 0,
+            fifo_stats: FifoStats::default(),
             _state: PhantomData,
         }
     }
+
+    /// Releases the bus, keeping the software settings of the driver.
+    ///
+    /// The device is left untouched, so the bus can be used for something else (or shut down)
+    /// and the driver rebuilt later with [`Self::from_parts`] without reinitializing the sensor.
+    ///
+    /// # Returns
+    ///
+    /// * `(B, DriverState)`: The bus and the software settings (user correction, temperature
+    ///   compensation, spike filter, calibration, tare, AH/QVAR sensitivity, active conversion
+    ///   parameters, recovery policy, sample sequence number and FIFO statistics).
+    pub fn release(self) -> (B, DriverState) {
+        let state = DriverState {
+            #[cfg(feature = "float")]
+            temp_comp: self.temp_comp,
+            #[cfg(feature = "float")]
+            user_correction: self.user_correction,
+            #[cfg(feature = "float")]
+            spike_filter: self.spike_filter,
+            #[cfg(feature = "float")]
+            tare_hpa: self.tare_hpa,
+            #[cfg(feature = "float")]
+            calibration: self.calibration,
+            #[cfg(feature = "qvar")]
+            qvar_sensitivity: self.qvar_sensitivity,
+            md: self.md,
+            drv_rdy: self.drv_rdy,
+            recovery: self.recovery,
+            seq: self.seq,
+            fifo_stats: self.fifo_stats,
+        };
+        (self.bus, state)
+    }
+
+    /// Rebuilds a driver released with [`Self::release`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bus`: The bus that implements BusOperation.
+    /// * `state`: The software settings returned by [`Self::release`].
+    ///
+    /// # Returns
+    ///
+    /// * `Self`: Returns an instance of `Ilps22qs`.
+    pub fn from_parts(bus: B, state: DriverState) -> Self {
+        // Destructured so that a field added to `DriverState` cannot be left out here
+        let DriverState {
+            #[cfg(feature = "float")]
+            temp_comp,
+            #[cfg(feature = "float")]
+            user_correction,
+            #[cfg(feature = "float")]
+            spike_filter,
+            #[cfg(feature = "float")]
+            tare_hpa,
+            #[cfg(feature = "float")]
+            calibration,
+            #[cfg(feature = "qvar")]
+            qvar_sensitivity,
+            md,
+            drv_rdy,
+            recovery,
+            seq,
+            fifo_stats,
+        } = state;
+        Self {
+            #[cfg(feature = "float")]
+            temp_comp,
+            #[cfg(feature = "float")]
+            user_correction,
+            #[cfg(feature = "float")]
+            spike_filter,
+            #[cfg(feature = "float")]
+            tare_hpa,
+            #[cfg(feature = "float")]
+            calibration,
+            #[cfg(feature = "qvar")]
+            qvar_sensitivity,
+            md,
+            drv_rdy,
+            recovery,
+            seq,
+            fifo_stats,
+            ..Self::from_bus(bus)
+        }
+    }
+}
+
+/// Software settings of a driver, returned by [`Ilps22qs::release`].
+#[derive(Clone, Copy)]
+pub struct DriverState {
+    #[cfg(feature = "float")]
+    temp_comp: Option<TempCompensation>,
+    #[cfg(feature = "float")]
+    user_correction: UserCorrection,
+    #[cfg(feature = "float")]
+    spike_filter: Option<SpikeFilter>,
+    #[cfg(feature = "float")]
+    tare_hpa: Option<f32>,
+    #[cfg(feature = "float")]
+    calibration: Calibration,
+    #[cfg(feature = "qvar")]
+    qvar_sensitivity: f32,
+    md: Md,
+    drv_rdy: bool,
+    recovery: Option<RecoveryPolicy>,
+    seq: u32,
+    fifo_stats: FifoStats,
 }
 
 #[bisync]
-impl<P, T> Ilps22qs<spi::SpiBus<P>, T, OnState>
+impl<P> Ilps22qs<spi::SpiBus<P>, OnState>
 where
     P: SpiDevice,
-    T: DelayNs,
 {
     /// Constructor method for using the SPI bus.
     ///
     /// # Arguments
     ///
     /// * `spi`: The SPI peripheral.
-    /// * `tim`: The timer of the COMPONENT sensor.
     ///
     /// # Returns
     ///
     /// * `Self`: Returns an instance of `Ilps22qs`.
-    pub fn new_spi(spi: P, tim: T) -> Self {
+    pub fn new_spi(spi: P) -> Self {
         // Initialize the SPI bus
-        let bus = spi::SpiBus::new(spi);
-        Self {
-            bus,
-            tim,
-            _state: PhantomData,
-        }
+        Self::from_bus(spi::SpiBus::new(spi))
+    }
+
+    /// Checks whether a responsive ILPS22QS is selected by an SPI device.
+    ///
+    /// The device is only borrowed and not configured, see [`Self::probe`]. A missing sensor
+    /// usually reads as `0x00` or `0xFF` and is reported as absent.
+    ///
+    /// # Arguments
+    ///
+    /// * `spi`: The SPI device.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, P::Error>`
+    ///     * `bool`: `true` if the device returns the ILPS22QS WHO_AM_I value.
+    ///     * `Err`: Returns the error of the SPI device.
+    pub async fn probe_spi(spi: &mut P) -> Result<bool, P::Error> {
+        Ilps22qs::probe(&mut spi::SpiBus::new(spi)).await
+    }
+}
+
+#[bisync]
+impl<P, C> Ilps22qs<SoftCsSpi<P, C>, OnState>
+where
+    P: RawSpiBus,
+    C: OutputPin,
+{
+    /// Constructor method for using an SPI bus with a software-driven chip select.
+    ///
+    /// This is meant for HALs or boards lacking an `SpiDevice` for the sensor: the chip select
+    /// is driven through `cs` around every transaction, see [`SoftCsSpi`].
+    ///
+    /// # Arguments
+    ///
+    /// * `spi`: The SPI bus peripheral.
+    /// * `cs`: The chip select pin of the COMPONENT sensor, initialized high.
+    ///
+    /// # Returns
+    ///
+    /// * `Self`: Returns an instance of `Ilps22qs`.
+    pub fn new_spi_soft_cs(spi: P, cs: C) -> Self {
+        Self::from_bus(SoftCsSpi::new(spi, cs))
     }
 }
 
 #[bisync]
-impl<B: BusOperation, T: DelayNs, S: SensorState> SensorOperation for Ilps22qs<B, T, S> {
+impl<B: BusOperation, S: SensorState> SensorOperation for Ilps22qs<B, S> {
     type Error = Error<B::Error>;
 
     #[inline]
     async fn read_from_register(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Error<B::Error>> {
+        let len = buf.len();
         self.bus
             .read_from_register(reg, buf)
             .await
-            .map_err(Error::Bus)
+            .map_err(|source| {
+                Error::Bus(BusError {
+                    source,
+                    op: BusOp::Read,
+                    reg,
+                    len,
+                })
+            })
     }
 
     #[inline]
@@ -139,12 +467,42 @@ impl<B: BusOperation, T: DelayNs, S: SensorState> SensorOperation for Ilps22qs<B
         self.bus
             .write_to_register(reg, buf)
             .await
-            .map_err(Error::Bus)
+            .map_err(|source| {
+                Error::Bus(BusError {
+                    source,
+                    op: BusOp::Write,
+                    reg,
+                    len: buf.len(),
+                })
+            })
     }
 }
 
 #[bisync]
-impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
+impl<B: BusOperation> Ilps22qs<B, OnState> {
+    /// Checks whether a responsive ILPS22QS is reachable through a bus.
+    ///
+    /// This function reads the WHO_AM_I register and compares it with [`ILPS22QS_ID`], without
+    /// taking ownership of the bus nor writing to the device, so a driver only needs to be built
+    /// once a sensor is found. See [`Self::probe_i2c`] and [`Self::probe_spi`] for the
+    /// embedded-hal peripherals.
+    ///
+    /// # Arguments
+    ///
+    /// * `bus`: The bus that implements BusOperation.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, B::Error>`
+    ///     * `bool`: `true` if the WHO_AM_I value matches.
+    ///     * `Err`: Returns the error of the bus.
+    pub async fn probe(bus: &mut B) -> Result<bool, B::Error> {
+        let mut whoami = [0];
+        bus.read_from_register(Reg::WhoAmI as u8, &mut whoami)
+            .await?;
+        Ok(whoami == [ILPS22QS_ID])
+    }
+
     /// Retrieves the "Who am I" ID value of the device.
     ///
     /// This function reads the device's identification register to obtain the "Who am I" ID value,
@@ -220,7 +578,8 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let if_ctrl = IfCtrl::read(self).await?;
         let i3c_if_ctrl = I3cIfCtrl::read(self).await?;
 
-        let interface = Interface::try_from(if_ctrl.i2c_i3c_dis() << 1).unwrap_or_default();
+        let interface = Interface::try_from((if_ctrl.i2c_i3c_dis() << 1) | if_ctrl.en_spi_read())
+            .unwrap_or_default();
         let filter = Filter::try_from(i3c_if_ctrl.asf_on()).unwrap_or_default();
 
         Ok(BusMode { interface, filter })
@@ -238,6 +597,9 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     /// * `val`: An instance of `Init` that specifies the desired initialization procedure.
     ///   The options include booting the device, performing a software reset, or preparing the
     ///   device for operation.
+    /// * `delay`: The timing peripheral used to wait for the boot and reset procedures to
+    ///   complete. It is only borrowed for the duration of the call, so it can be shared with
+    ///   other drivers.
     ///
     /// # Returns
     ///
@@ -253,7 +615,11 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
     /// * `Error::Boot`: Occurs if the boot procedure does not complete successfully within the expected time.
     /// * `Error::SwReset`: Occurs if the software reset procedure does not complete successfully within the expected time.
-    pub async fn init_set(&mut self, val: Init) -> Result<(), Error<B::Error>> {
+    pub async fn init_set<D: DelayNs>(
+        &mut self,
+        val: Init,
+        delay: &mut D,
+    ) -> Result<(), Error<B::Error>> {
         let mut ctrl_reg2 = CtrlReg2::read(self).await?;
         let mut ctrl_reg3 = CtrlReg3::read(self).await?;
 
@@ -270,7 +636,7 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
                         break;
                     }
 
-                    self.tim.delay_ms(10).await; // 10ms of boot time
+                    delay.delay_ms(BOOT_TIME_MS).await;
                     cnt += 1;
                 }
 
@@ -286,17 +652,19 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
                 while cnt < 5 {
                     let status = self.status_get().await?;
 
-                    if status.sw_reset == PROPERTY_DISABLE {
+                    if !status.sw_reset {
                         break;
                     }
 
-                    self.tim.delay_us(50).await;
+                    delay.delay_us(SW_RESET_POLL_US).await;
                     cnt += 1;
                 }
 
                 if cnt >= 5 {
                     return Err(Error::SwReset);
                 }
+                self.md = Md::default();
+                self.drv_rdy = false;
             }
             Init::DrvRdy => {
                 ctrl_reg2.set_bdu(PROPERTY_ENABLE);
@@ -304,12 +672,291 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
 
                 ctrl_reg2.write(self).await?;
                 ctrl_reg3.write(self).await?;
+                self.drv_rdy = true;
             }
         }
 
         Ok(())
     }
 
+    /// Performs a software reset and waits for its completion.
+    ///
+    /// This function replaces the `init_set(Init::Reset)` call followed by a busy-wait on the
+    /// `sw_reset` status flag: the flag is polled every 50 µs until it is cleared or `timeout`
+    /// elapses.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay`: The timing peripheral used between two polls of the reset status.
+    /// * `timeout`: The longest time to wait for the reset to complete, either in microseconds
+    ///   (`u32`) or, with the `fugit` feature, as a `fugit` duration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: The device completed the software reset.
+    ///     * `Err`: Returns an error if the operation fails or times out.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
+    /// * `Error::SwReset`: Occurs if the reset is still in progress after `timeout`.
+    pub async fn reset_and_wait<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout: impl Into<Timeout>,
+    ) -> Result<(), Error<B::Error>> {
+        let mut ctrl_reg2 = CtrlReg2::read(self).await?;
+        ctrl_reg2.set_swreset(PROPERTY_ENABLE);
+        ctrl_reg2.write(self).await?;
+
+        if self
+            .status_wait(delay, timeout.into(), |status| !status.sw_reset)
+            .await?
+        {
+            self.md = Md::default();
+            self.drv_rdy = false;
+            Ok(())
+        } else {
+            Err(Error::SwReset)
+        }
+    }
+
+    /// Reloads the trimming parameters and waits for the boot to complete.
+    ///
+    /// This function sets the `boot` bit and polls the boot status every 50 µs until the boot is
+    /// over or `timeout` elapses.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay`: The timing peripheral used between two polls of the boot status.
+    /// * `timeout`: The longest time to wait for the boot to complete, either in microseconds
+    ///   (`u32`) or, with the `fugit` feature, as a `fugit` duration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: The device completed the boot.
+    ///     * `Err`: Returns an error if the operation fails or times out.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
+    /// * `Error::Boot`: Occurs if the boot is still in progress after `timeout`.
+    pub async fn boot_and_wait<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout: impl Into<Timeout>,
+    ) -> Result<(), Error<B::Error>> {
+        let mut ctrl_reg2 = CtrlReg2::read(self).await?;
+        ctrl_reg2.set_boot(PROPERTY_ENABLE);
+        ctrl_reg2.write(self).await?;
+
+        if self
+            .status_wait(delay, timeout.into(), |status| !status.boot)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(Error::Boot)
+        }
+    }
+
+    /// Checks whether the device lost the configuration applied through the driver.
+    ///
+    /// An unexpected reset, e.g. a brown-out, brings the device back to its default settings while
+    /// it keeps answering on the bus with the right WHO_AM_I, so the samples read afterwards would
+    /// be converted with a wrong full scale. This function compares CTRL_REG1 to CTRL_REG3 with the
+    /// parameters last applied with [`Self::mode_set`] and, if `Init::DrvRdy` was applied, with the
+    /// BDU setting. A reset cannot be detected while the driver keeps the default parameters and
+    /// `Init::DrvRdy` was not applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: The device keeps the applied configuration.
+    ///     * `Err`: Returns an error if the operation fails or the configuration was lost.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
+    /// * `Error::DeviceReset`: Occurs if the device configuration no longer matches the driver.
+    pub async fn reset_check(&mut self) -> Result<(), Error<B::Error>> {
+        if self.reset_detected().await? {
+            Err(Error::DeviceReset)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Re-applies the configuration kept by the driver if the device lost it.
+    ///
+    /// This function performs the detection of [`Self::reset_check`] and, on a reset, applies
+    /// `Init::DrvRdy` again if it was applied before, followed by the conversion parameters of
+    /// [`Self::active_mode`]. The other settings (FIFO, interrupts, thresholds, reference and
+    /// offset) are not cached by the driver: restore them with [`Self::config_restore`] from a
+    /// [`Self::config_snapshot`] taken beforehand when needed.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, Error<B::Error>>`
+    ///     * `bool`: `true` if a reset was detected and the configuration re-applied.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
+    pub async fn reset_recover(&mut self) -> Result<bool, Error<B::Error>> {
+        if !self.reset_detected().await? {
+            return Ok(false);
+        }
+
+        self.config_reapply().await?;
+        Ok(true)
+    }
+
+    async fn config_reapply(&mut self) -> Result<(), Error<B::Error>> {
+        if self.drv_rdy {
+            let mut ctrl_reg2 = CtrlReg2::read(self).await?;
+            let mut ctrl_reg3 = CtrlReg3::read(self).await?;
+            ctrl_reg2.set_bdu(PROPERTY_ENABLE);
+            ctrl_reg3.set_if_add_inc(PROPERTY_ENABLE);
+            ctrl_reg2.write(self).await?;
+            ctrl_reg3.write(self).await?;
+        }
+        let md = self.md;
+        self.mode_set(&md).await
+    }
+
+    /// Configures the recovery policy of the read paths.
+    ///
+    /// When set, the consecutive bus errors of the sample reads are counted, and the action of the
+    /// policy is taken once its threshold is reached. The reads covered are those of
+    /// [`Self::data_get`], [`Self::data_get_raw`], [`Self::data_get_tagged`],
+    /// [`Self::data_get_fast`], [`Self::pressure_get_fast`], [`Self::temp_data_get`],
+    /// [`Self::ah_qvar_data_get`], [`Self::fifo_data_get`], [`Self::fifo_data_get_decimated`] and
+    /// [`Self::fifo_data_get_raw`], together with the functions built on them. Installing a
+    /// policy restarts the count.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: The recovery policy to apply, or `None` to report bus errors only.
+    pub fn recovery_policy_set(&mut self, val: Option<RecoveryPolicy>) {
+        self.recovery = val;
+        self.bus_errors = 0;
+    }
+
+    /// Retrieves the recovery policy of the read paths.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<RecoveryPolicy>`: The active policy, or `None` if disabled.
+    pub fn recovery_policy_get(&self) -> Option<RecoveryPolicy> {
+        self.recovery
+    }
+
+    /// Returns the number of consecutive bus errors counted by the recovery policy.
+    pub const fn bus_errors_get(&self) -> u8 {
+        self.bus_errors
+    }
+
+    /// Accounts for the result of a bus read of a read path, taking the recovery action if due.
+    async fn recovery_check<T>(
+        &mut self,
+        result: Result<T, Error<B::Error>>,
+    ) -> Result<T, Error<B::Error>> {
+        let Some(policy) = self.recovery else {
+            return result;
+        };
+        let Err(Error::Bus(error)) = result else {
+            if result.is_ok() {
+                self.bus_errors = 0;
+            }
+            return result;
+        };
+
+        self.bus_errors = self.bus_errors.saturating_add(1);
+        if self.bus_errors < policy.max_errors {
+            return Err(Error::Bus(error));
+        }
+        let recovered = match policy.action {
+            RecoveryAction::Reprobe => Self::probe(&mut self.bus).await.unwrap_or(false),
+            RecoveryAction::Reinit => {
+                Self::probe(&mut self.bus).await.unwrap_or(false)
+                    && self.config_reapply().await.is_ok()
+            }
+            RecoveryAction::Fatal => return Err(Error::Fatal),
+        };
+        if recovered {
+            self.bus_errors = 0;
+        }
+        Err(Error::Bus(error))
+    }
+
+    async fn reset_detected(&mut self) -> Result<bool, Error<B::Error>> {
+        let (ctrl_reg2, md) = self.mode_read().await?;
+        Ok(md != self.md || (self.drv_rdy && ctrl_reg2.bdu() == PROPERTY_DISABLE))
+    }
+
+    /// Waits for a new pressure sample.
+    ///
+    /// This function polls the pressure data-ready flag every 50 µs until it is set or `timeout`
+    /// elapses.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay`: The timing peripheral used between two polls of the status.
+    /// * `timeout`: The longest time to wait for the sample, either in microseconds (`u32`) or,
+    ///   with the `fugit` feature, as a `fugit` duration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: A new pressure sample is available.
+    ///     * `Err`: Returns an error if the operation fails or times out.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
+    /// * `Error::Timeout`: Occurs if no new sample is available after `timeout`.
+    pub async fn data_ready_wait<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout: impl Into<Timeout>,
+    ) -> Result<(), Error<B::Error>> {
+        if self
+            .status_wait(delay, timeout.into(), |status| status.drdy_pres)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    /// Polls the status every 50 µs until `done` or `timeout`, returning whether `done` was met.
+    async fn status_wait<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout: Timeout,
+        done: fn(&Stat) -> bool,
+    ) -> Result<bool, Error<B::Error>> {
+        let timeout_us = timeout.as_micros();
+        let mut waited_us = 0;
+        loop {
+            if done(&self.status_get().await?) {
+                return Ok(true);
+            }
+            if waited_us >= timeout_us {
+                return Ok(false);
+            }
+
+            let step_us = (timeout_us - waited_us).min(50);
+            delay.delay_us(step_us).await;
+            waited_us += step_us;
+        }
+    }
+
     /// Retrieves the current status of the device.
     ///
     /// This function reads multiple registers to gather comprehensive status information about the device,
@@ -336,14 +983,14 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let interrupt_cfg = InterruptCfg::read(self).await?;
 
         Ok(Stat {
-            sw_reset: ctrl_reg2.swreset(),
-            boot: int_source.boot_on(),
-            drdy_pres: status.p_da(),
-            drdy_temp: status.t_da(),
-            ovr_pres: status.p_or(),
-            ovr_temp: status.t_or(),
-            end_meas: !ctrl_reg2.oneshot(),
-            ref_done: !interrupt_cfg.autozero(),
+            sw_reset: ctrl_reg2.swreset() == PROPERTY_ENABLE,
+            boot: int_source.boot_on() == PROPERTY_ENABLE,
+            drdy_pres: status.p_da() == PROPERTY_ENABLE,
+            drdy_temp: status.t_da() == PROPERTY_ENABLE,
+            ovr_pres: status.p_or() == PROPERTY_ENABLE,
+            ovr_temp: status.t_or() == PROPERTY_ENABLE,
+            end_meas: ctrl_reg2.oneshot() == PROPERTY_DISABLE,
+            ref_done: interrupt_cfg.autozero() == PROPERTY_DISABLE,
         })
     }
 
@@ -370,8 +1017,8 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///   successful writing of the pin configuration settings.
     pub async fn pin_conf_set(&mut self, val: &PinConf) -> Result<(), Error<B::Error>> {
         let mut if_ctrl = IfCtrl::read(self).await?;
-        if_ctrl.set_sda_pu_en(val.sda_pull_up);
-        if_ctrl.set_cs_pu_dis(!val.cs_pull_up);
+        if_ctrl.set_sda_pu_en(val.sda_pull_up as u8);
+        if_ctrl.set_cs_pu_dis(!val.cs_pull_up as u8);
         if_ctrl.write(self).await
     }
 
@@ -394,8 +1041,8 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     pub async fn pin_conf_get(&mut self) -> Result<PinConf, Error<B::Error>> {
         let if_ctrl = IfCtrl::read(self).await?;
 
-        let sda_pull_up = if_ctrl.sda_pu_en();
-        let cs_pull_up = !if_ctrl.cs_pu_dis();
+        let sda_pull_up = if_ctrl.sda_pu_en() == PROPERTY_ENABLE;
+        let cs_pull_up = if_ctrl.cs_pu_dis() == PROPERTY_DISABLE;
 
         Ok(PinConf {
             sda_pull_up,
@@ -427,44 +1074,115 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let fifo_status2 = FifoStatus2::read(self).await?;
 
         Ok(AllSources {
-            drdy_pres: status.p_da(),
-            drdy_temp: status.t_da(),
-            over_pres: int_source.ph(),
-            under_pres: int_source.pl(),
-            thrsld_pres: int_source.ia(),
-            fifo_full: fifo_status2.fifo_full_ia(),
-            fifo_ovr: fifo_status2.fifo_ovr_ia(),
-            fifo_th: fifo_status2.fifo_wtm_ia(),
+            drdy_pres: status.p_da() == PROPERTY_ENABLE,
+            drdy_temp: status.t_da() == PROPERTY_ENABLE,
+            over_pres: int_source.ph() == PROPERTY_ENABLE,
+            under_pres: int_source.pl() == PROPERTY_ENABLE,
+            thrsld_pres: int_source.ia() == PROPERTY_ENABLE,
+            fifo_full: fifo_status2.fifo_full_ia() == PROPERTY_ENABLE,
+            fifo_ovr: fifo_status2.fifo_ovr_ia() == PROPERTY_ENABLE,
+            fifo_th: fifo_status2.fifo_wtm_ia() == PROPERTY_ENABLE,
         })
     }
 
-    /// Configures the sensor conversion parameters.
+    /// Polls the device status and queues the decoded events.
     ///
-    /// This function sets various sensor conversion parameters, including output data rate (ODR),
-    /// averaging, low-pass filter settings, and full-scale mode. It also handles interleaved mode
-    /// settings for both regular operation and FIFO configuration, allowing for flexible sensor
-    /// data processing tailored to specific application needs.
+    /// This function reads the status sources like [`Self::all_sources_get`] and appends every
+    /// active indicator to `queue` as an [`Event`], so the superloop can detect events in one
+    /// place and handle them elsewhere. Events that do not fit in the queue are dropped.
     ///
     /// # Parameters
     ///
-    /// * `val`: A reference to `Md`, which contains the desired sensor conversion parameters.
-    ///   This includes settings for ODR, averaging, low-pass filter, full-scale mode, and interleaved
-    ///   mode configuration.
+    /// * `queue`: A mutable reference to the queue receiving the events.
     ///
     /// # Returns
     ///
-    /// * `Result<(), Error<B::Error>>`
-    ///     * `Ok(())`: Indicates successful configuration of the sensor conversion parameters.
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `usize`: The number of events queued.
     ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
     ///
     /// # Errors
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
-    ///   successful writing of the sensor conversion settings.
-    pub async fn mode_set(&mut self, val: &Md) -> Result<(), Error<B::Error>> {
-        let mut ctrl_reg1 = CtrlReg1::read(self).await?;
-        let mut ctrl_reg2 = CtrlReg2::read(self).await?;
-        let mut ctrl_reg3 = CtrlReg3::read(self).await?;
+    ///   successful reading of the status registers.
+    #[cfg(feature = "heapless")]
+    pub async fn events_poll<const N: usize>(
+        &mut self,
+        queue: &mut heapless::Deque<Event, N>,
+    ) -> Result<usize, Error<B::Error>> {
+        let sources = self.all_sources_get().await?;
+        Ok(sources
+            .events()
+            .map_while(|event| queue.push_back(event).ok())
+            .count())
+    }
+
+    /// Polls the device status and queues the decoded events with the time they were detected.
+    ///
+    /// This function behaves as [`Self::events_poll`], stamping every event queued by the call
+    /// with the time read from `clock` once the status registers are read.
+    ///
+    /// # Parameters
+    ///
+    /// * `clock`: The time source, in microseconds.
+    /// * `queue`: A mutable reference to the queue receiving the stamped events.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `usize`: The number of events queued.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the status registers.
+    #[cfg(feature = "heapless")]
+    pub async fn events_poll_clocked<T: Timestamp, const N: usize>(
+        &mut self,
+        clock: &mut T,
+        queue: &mut heapless::Deque<(u64, Event), N>,
+    ) -> Result<usize, Error<B::Error>> {
+        let sources = self.all_sources_get().await?;
+        let timestamp_us = clock.now_us();
+        Ok(sources
+            .events()
+            .map_while(|event| queue.push_back((timestamp_us, event)).ok())
+            .count())
+    }
+
+    /// Configures the sensor conversion parameters.
+    ///
+    /// This function sets various sensor conversion parameters, including output data rate (ODR),
+    /// averaging, low-pass filter settings, and full-scale mode. It also handles interleaved mode
+    /// settings for both regular operation and FIFO configuration, allowing for flexible sensor
+    /// data processing tailored to specific application needs. When register address
+    /// auto-increment is enabled (see `Init::DrvRdy`), CTRL_REG1 to CTRL_REG3 are written in a
+    /// single multi-byte transaction.
+    ///
+    /// The driver keeps the applied parameters, see [`Self::active_mode`], and converts the
+    /// samples returned by [`Self::data_get`] and [`Self::fifo_data_get`] with them.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: A reference to `Md`, which contains the desired sensor conversion parameters.
+    ///   This includes settings for ODR, averaging, low-pass filter, full-scale mode, and interleaved
+    ///   mode configuration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: Indicates successful configuration of the sensor conversion parameters.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the sensor conversion settings.
+    pub async fn mode_set(&mut self, val: &Md) -> Result<(), Error<B::Error>> {
+        let mut ctrl_reg1 = CtrlReg1::read(self).await?;
+        let mut ctrl_reg2 = CtrlReg2::read(self).await?;
+        let mut ctrl_reg3 = CtrlReg3::read(self).await?;
 
         let mut odr_save = PROPERTY_DISABLE;
         let mut ah_qvar_en_save = PROPERTY_DISABLE;
@@ -485,12 +1203,12 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         }
 
         // Set interleaved mode (0 or 1)
-        ctrl_reg3.set_ah_qvar_p_auto_en(val.interleaved_mode);
+        ctrl_reg3.set_ah_qvar_p_auto_en(val.interleaved_mode as u8);
         ctrl_reg3.write(self).await?;
 
         // Set FIFO interleaved mode (0 or 1)
         let mut fifo_ctrl = FifoCtrl::read(self).await?;
-        fifo_ctrl.set_ah_qvar_p_fifo_en(val.interleaved_mode);
+        fifo_ctrl.set_ah_qvar_p_fifo_en(val.interleaved_mode as u8);
         fifo_ctrl.write(self).await?;
 
         if ah_qvar_en_save != PROPERTY_DISABLE {
@@ -506,12 +1224,26 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         ctrl_reg1.set_odr(val.odr as u8);
         ctrl_reg1.set_avg(val.avg as u8);
         ctrl_reg2.set_en_lpfp(val.lpf as u8 & 0x01);
-        ctrl_reg2.set_lfpf_cfg((val.lpf as u8 & 0x02) >> 2);
+        ctrl_reg2.set_lfpf_cfg((val.lpf as u8 & 0x02) >> 1);
         ctrl_reg2.set_fs_mode(val.fs as u8);
 
-        ctrl_reg1.write(self).await?;
-        ctrl_reg2.write(self).await?;
-        ctrl_reg3.write(self).await
+        if ctrl_reg3.if_add_inc() == PROPERTY_DISABLE {
+            ctrl_reg1.write(self).await?;
+            ctrl_reg2.write(self).await?;
+            ctrl_reg3.write(self).await?;
+        } else {
+            // CTRL_REG1..CTRL_REG3 are contiguous: update them in a single auto-incremented write
+            let ctrl_regs = [
+                ctrl_reg1.into_bits(),
+                ctrl_reg2.into_bits(),
+                ctrl_reg3.into_bits(),
+            ];
+            self.write_to_register(Reg::CtrlReg1 as u8, &ctrl_regs)
+                .await?;
+        }
+
+        self.md = *val;
+        Ok(())
     }
 
     /// Retrieves the current sensor conversion parameters.
@@ -521,6 +1253,10 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     /// full-scale mode, and interleaved mode. It provides insight into how the device is currently configured
     /// for data processing and acquisition.
     ///
+    /// The driver keeps converting with the parameters it applied, so a device reset to its
+    /// defaults can still be detected by [`Self::reset_check`]; use [`Self::mode_adopt`] to take
+    /// over a device configured by other code.
+    ///
     /// # Returns
     ///
     /// * `Result<Md, Error<B::Error>>`
@@ -533,6 +1269,34 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful reading of the sensor conversion settings.
     pub async fn mode_get(&mut self) -> Result<Md, Error<B::Error>> {
+        let (_, md) = self.mode_read().await?;
+        Ok(md)
+    }
+
+    /// Adopts the conversion parameters the device is configured with.
+    ///
+    /// This function reads the parameters like [`Self::mode_get`] and keeps them to convert the
+    /// samples, as if they had been applied with [`Self::mode_set`]. Call it once when taking
+    /// over a device configured by other code, e.g. a bootloader.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Md, Error<B::Error>>`
+    ///     * `Md`: The adopted sensor conversion parameters.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor conversion settings.
+    pub async fn mode_adopt(&mut self) -> Result<Md, Error<B::Error>> {
+        let (_, md) = self.mode_read().await?;
+        self.md = md;
+        Ok(md)
+    }
+
+    /// Reads the conversion parameters, together with CTRL_REG2, without updating the driver.
+    async fn mode_read(&mut self) -> Result<(CtrlReg2, Md), Error<B::Error>> {
         let ctrl_reg1 = CtrlReg1::read(self).await?;
         let ctrl_reg2 = CtrlReg2::read(self).await?;
         let ctrl_reg3 = CtrlReg3::read(self).await?;
@@ -541,28 +1305,93 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let odr = Odr::try_from(ctrl_reg1.odr()).unwrap_or_default();
         let avg = Avg::try_from(ctrl_reg1.avg()).unwrap_or_default();
         let lpf =
-            Lpf::try_from((ctrl_reg2.lfpf_cfg() << 2) | ctrl_reg2.en_lpfp()).unwrap_or_default();
+            Lpf::try_from((ctrl_reg2.lfpf_cfg() << 1) | ctrl_reg2.en_lpfp()).unwrap_or_default();
 
-        Ok(Md {
-            interleaved_mode: ctrl_reg3.ah_qvar_p_auto_en(),
+        let md = Md {
+            interleaved_mode: ctrl_reg3.ah_qvar_p_auto_en() == PROPERTY_ENABLE,
             fs,
             odr,
             avg,
             lpf,
-        })
+        };
+        Ok((ctrl_reg2, md))
     }
 
-    /// Initiates a software trigger for a One-Shot sensor conversion.
+    /// Returns the conversion parameters the driver converts the samples with.
     ///
-    /// This function enables a One-Shot conversion mode, allowing the device to perform a single
-    /// measurement based on the provided sensor conversion parameters. The One-Shot mode is useful
-    /// for applications that require precise, on-demand measurements rather than continuous data
-    /// acquisition.
+    /// These are the parameters last applied with [`Self::mode_set`] or adopted with
+    /// [`Self::mode_adopt`], and the device defaults after a software reset. No bus access is done.
+    pub const fn active_mode(&self) -> Md {
+        self.md
+    }
+
+    /// Retrieves the current full-scale mode.
+    ///
+    /// This function reads CTRL_REG2 only, so code receiving the driver from elsewhere can learn
+    /// the active pressure range, e.g. to pick its [`Sensitivity`], without tracking the `Md`
+    /// passed to [`Self::mode_set`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Fs, Error<B::Error>>`
+    ///     * `Fs`: The full-scale mode the device is configured with.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the full-scale setting.
+    pub async fn full_scale_get(&mut self) -> Result<Fs, Error<B::Error>> {
+        let ctrl_reg2 = CtrlReg2::read(self).await?;
+
+        Ok(Fs::try_from(ctrl_reg2.fs_mode()).unwrap_or_default())
+    }
+
+    /// Configures the sensor for a current budget and a signal bandwidth.
+    ///
+    /// This function selects an ODR/AVG pair with [`Md::for_power_budget`] and applies it with
+    /// [`Self::mode_set`]. The full-scale, low-pass filter and interleaved settings are taken from
+    /// `base`. Nothing is written if the requirements cannot be met.
     ///
     /// # Parameters
     ///
-    /// * `md`: A reference to `Md`, which contains the sensor conversion parameters. The function
-    ///   checks if the `odr` (output data rate) is set to `OneShot` before triggering the conversion.
+    /// * `base`: A reference to `Md` providing the settings not driven by the budget.
+    /// * `budget_ua`: The available supply current, in µA.
+    /// * `bandwidth_hz`: The highest signal frequency to be captured, in Hz.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<Md>, Error<B::Error>>`
+    ///     * `Some(Md)`: The applied configuration.
+    ///     * `None`: No configuration fits the budget; the device is left untouched.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the sensor conversion settings.
+    #[cfg(feature = "float")]
+    pub async fn mode_set_for_power_budget(
+        &mut self,
+        base: &Md,
+        budget_ua: f32,
+        bandwidth_hz: f32,
+    ) -> Result<Option<Md>, Error<B::Error>> {
+        let Some(md) = base.for_power_budget(budget_ua, bandwidth_hz) else {
+            return Ok(None);
+        };
+
+        self.mode_set(&md).await?;
+        Ok(Some(md))
+    }
+
+    /// Initiates a software trigger for a One-Shot sensor conversion.
+    ///
+    /// This function enables a One-Shot conversion mode, allowing the device to perform a single
+    /// measurement when the conversion parameters applied with [`Self::mode_set`] select
+    /// `Odr::OneShot`; otherwise the device is already sampling and nothing is written. The One-Shot
+    /// mode is useful for applications that require precise, on-demand measurements rather than
+    /// continuous data acquisition.
     ///
     /// # Returns
     ///
@@ -574,23 +1403,80 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful writing of the One-Shot trigger command.
-    pub async fn trigger_sw(&mut self, md: &Md) -> Result<(), Error<B::Error>> {
-        if md.odr == Odr::OneShot {
-            let mut ctrl_reg2 = CtrlReg2::read(self).await?;
-            ctrl_reg2.set_oneshot(PROPERTY_ENABLE);
-            ctrl_reg2.write(self).await?;
+    pub async fn trigger_sw(&mut self) -> Result<(), Error<B::Error>> {
+        if self.md.odr == Odr::OneShot {
+            self.oneshot_start().await?;
         }
         Ok(())
     }
 
+    /// Sets the ONESHOT bit, starting a conversion regardless of the applied parameters.
+    pub(crate) async fn oneshot_start(&mut self) -> Result<(), Error<B::Error>> {
+        let mut ctrl_reg2 = CtrlReg2::read(self).await?;
+        ctrl_reg2.set_oneshot(PROPERTY_ENABLE);
+        ctrl_reg2.write(self).await
+    }
+
+    /// Starts continuous sampling at the output data rate of the given conversion parameters.
+    ///
+    /// This function only updates the ODR field of the device, leaving averaging, filtering,
+    /// full-scale, FIFO and interrupt configuration untouched. Together with [`Self::stop_sampling`]
+    /// it allows duty-cycled acquisition without re-issuing a full [`Self::mode_set`]. If `md.odr`
+    /// is `Odr::OneShot`, the device stays in power-down; use [`Self::trigger_sw`] instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `md`: A reference to `Md`, whose `odr` field selects the sampling rate.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok`: Indicates that sampling has started.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the output data rate.
+    pub async fn start_sampling(&mut self, md: &Md) -> Result<(), Error<B::Error>> {
+        let mut ctrl_reg1 = CtrlReg1::read(self).await?;
+        ctrl_reg1.set_odr(md.odr as u8);
+        ctrl_reg1.write(self).await?;
+        self.md.odr = md.odr;
+        Ok(())
+    }
+
+    /// Stops continuous sampling and places the device in power-down.
+    ///
+    /// This function only clears the ODR field of the device, so that a later call to
+    /// [`Self::start_sampling`] resumes acquisition with the same configuration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok`: Indicates that the device is in power-down.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the output data rate.
+    pub async fn stop_sampling(&mut self) -> Result<(), Error<B::Error>> {
+        let mut ctrl_reg1 = CtrlReg1::read(self).await?;
+        ctrl_reg1.set_odr(Odr::OneShot as u8);
+        ctrl_reg1.write(self).await?;
+        self.md.odr = Odr::OneShot;
+        Ok(())
+    }
+
     ///
     /// This function modifies the AH/QVAR enable setting in the control register, allowing the user
     /// to activate or deactivate the AH/QVAR functionality.
     ///
     /// # Parameters
     ///
-    /// * `val`: A `u8` value that specifies whether to enable or disable the AH/QVAR function. The value
-    ///   is written to the `ah_qvar_en` field in the `CTRL_REG3` register.
+    /// * `val`: `true` to enable the AH/QVAR function, `false` to disable it. The value is written
+    ///   to the `ah_qvar_en` field in the `CTRL_REG3` register.
     ///
     /// # Returns
     ///
@@ -602,9 +1488,10 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful writing of the AH/QVAR enable setting.
-    pub async fn ah_qvar_en_set(&mut self, val: u8) -> Result<(), Error<B::Error>> {
+    #[cfg(feature = "qvar")]
+    pub async fn ah_qvar_en_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
         let mut ctrl_reg3 = CtrlReg3::read(self).await?;
-        ctrl_reg3.set_ah_qvar_en(val);
+        ctrl_reg3.set_ah_qvar_en(val as u8);
         ctrl_reg3.write(self).await
     }
 
@@ -615,17 +1502,18 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// # Returns
     ///
-    /// * `Result<u8, Error<B::Error>>`
-    ///     * `u8`: The current value of the `ah_qvar_en` field in the `CTRL_REG3` register, indicating
-    ///       whether the AH/QVAR function is enabled or disabled.
+    /// * `Result<bool, Error<B::Error>>`
+    ///     * `bool`: The current value of the `ah_qvar_en` field in the `CTRL_REG3` register, `true`
+    ///       when the AH/QVAR function is enabled.
     ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
     ///
     /// # Errors
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful reading of the AH/QVAR enable status.
-    pub async fn ah_qvar_en_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ok(CtrlReg3::read(self).await?.ah_qvar_en())
+    #[cfg(feature = "qvar")]
+    pub async fn ah_qvar_en_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ok(CtrlReg3::read(self).await?.ah_qvar_en() == PROPERTY_ENABLE)
     }
 
     /// Retrieves sensor data, including pressure and temperature measurements.
@@ -633,13 +1521,14 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     /// This function reads raw data from the sensor registers and processes it according to the specified
     /// sensor conversion parameters. It supports both pressure and AH/QVAR data retrieval, depending on
     /// the configuration, and converts the raw data into meaningful units such as hectopascals (hPa) and
-    /// degrees Celsius (°C).
-    ///
-    /// # Parameters
+    /// degrees Celsius (°C). The user correction installed with [`Self::user_correction_set`] is
+    /// applied first. If a temperature compensation is configured through
+    /// [`Self::temp_compensation_set`], it is applied to the pressure value, followed by the
+    /// user gain/offset of the applied [`Calibration`], the spike filter installed with
+    /// [`Self::spike_filter_set`] and the tare captured with [`Self::tare`].
     ///
-    /// * `md`: A reference to `Md`, which contains the sensor conversion parameters. These parameters
-    ///   include settings for full-scale mode, interleaved mode, and other conversion options that affect
-    ///   how the raw data is processed.
+    /// The conversion parameters are those applied with [`Self::mode_set`], so the full-scale and
+    /// interleaved settings cannot get out of sync with the device.
     ///
     /// # Returns
     ///
@@ -651,38 +1540,344 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful reading of the sensor data.
-    pub async fn data_get(&mut self, md: &Md) -> Result<Data, Error<B::Error>> {
-        let mut data = Data::default();
-        data.pressure.raw = self.pressure_raw_get().await?;
-
-        if md.interleaved_mode == PROPERTY_ENABLE {
-            if (data.pressure.raw & 0x1) == 0 {
-                // Data is a pressure sample
-                data.pressure.hpa = match md.fs {
-                    Fs::_1260hpa => from_fs1260_to_hpa(data.pressure.raw),
-                    Fs::_4060hpa => from_fs4000_to_hpa(data.pressure.raw),
-                };
-                data.ah_qvar.lsb = 0;
-            } else {
-                // Data is a AH_QVAR sample
-                data.ah_qvar.lsb = data.pressure.raw >> 8;
-                data.pressure.hpa = 0.;
+    #[cfg(feature = "float")]
+    pub async fn data_get(&mut self) -> Result<Data, Error<B::Error>> {
+        let md = self.md;
+        self.data_get_with(&md).await
+    }
+
+    /// Retrieves sensor data as [`Self::data_get`], converting it with `md` instead of the applied
+    /// conversion parameters. The parameters kept by the driver, used by the recovery policy, are
+    /// left untouched.
+    #[cfg(feature = "float")]
+    pub(crate) async fn data_get_with(&mut self, md: &Md) -> Result<Data, Error<B::Error>> {
+        let pressure = self.pressure_raw_get().await;
+        let pressure = self.recovery_check(pressure).await?;
+        let temperature = self.temperature_raw_get().await;
+        let temperature = self.recovery_check(temperature).await?;
+        let mut data = decode::data_from_raw(pressure, temperature, md);
+
+        if let Some(correction) = &self.user_correction.temperature {
+            data.heat.deg_c = correction.apply(data.heat.deg_c, data.heat.deg_c);
+        }
+        if !decode::is_ah_qvar_sample(pressure, md) {
+            if let Some(correction) = &self.user_correction.pressure {
+                data.pressure.hpa = correction.apply(data.pressure.hpa, data.heat.deg_c);
+            }
+            if let Some(comp) = &self.temp_comp {
+                data.pressure.hpa = comp.apply(data.pressure.hpa, data.heat.deg_c);
+            }
+            data.pressure.hpa = self.calibration.pressure_correct(data.pressure.hpa);
+            if let Some(filter) = &mut self.spike_filter {
+                data.pressure.hpa = filter.update(data.pressure.hpa);
+            }
+            if let Some(tare_hpa) = self.tare_hpa {
+                data.pressure.hpa -= tare_hpa;
             }
-        } else {
-            data.pressure.hpa = match md.fs {
-                Fs::_1260hpa => from_fs1260_to_hpa(data.pressure.raw),
-                Fs::_4060hpa => from_fs4000_to_hpa(data.pressure.raw),
-            };
-            data.ah_qvar.lsb = 0;
         }
 
-        // Temperature conversion
-        data.heat.raw = self.temperature_raw_get().await?;
-        data.heat.deg_c = from_lsb_to_celsius(data.heat.raw);
-
         Ok(data)
     }
 
+    /// Retrieves sensor data together with its sequence number and status flags.
+    ///
+    /// This function behaves as [`Self::data_get`], reading the `STATUS` register first: the
+    /// sequence number is incremented when a new pressure sample is available, while a sample
+    /// read before the next conversion is flagged as stale and keeps the previous number. The
+    /// overrun flag reports that a sample was overwritten before being read, i.e. dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Sample<Data>, Error<B::Error>>`
+    ///     * `Sample<Data>`: The processed sensor data with its metadata.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    #[cfg(feature = "float")]
+    pub async fn data_get_tagged(&mut self) -> Result<Sample<Data>, Error<B::Error>> {
+        let status = Status::read(self).await;
+        let status = self.recovery_check(status).await?;
+        let stale = status.p_da() == PROPERTY_DISABLE;
+        let data = self.data_get().await?;
+        if !stale {
+            self.seq = self.seq.wrapping_add(1);
+        }
+
+        Ok(Sample {
+            seq: self.seq,
+            overrun: status.p_or() == PROPERTY_ENABLE,
+            stale,
+            data,
+        })
+    }
+
+    /// Retrieves sensor data together with the time of its acquisition.
+    ///
+    /// This function behaves as [`Self::data_get`]; the time is read from `clock` right before
+    /// the output registers, so it is not delayed by the bus transfer.
+    ///
+    /// # Parameters
+    ///
+    /// * `clock`: The time source, in microseconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(u64, Data), Error<B::Error>>`
+    ///     * `(u64, Data)`: The acquisition time, in microseconds, and the processed sensor data.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    #[cfg(feature = "float")]
+    pub async fn data_get_clocked<T: Timestamp>(
+        &mut self,
+        clock: &mut T,
+    ) -> Result<(u64, Data), Error<B::Error>> {
+        let timestamp_us = clock.now_us();
+        let data = self.data_get().await?;
+        Ok((timestamp_us, data))
+    }
+
+    /// Retrieves the raw output data of the sensor.
+    ///
+    /// This function reads the pressure and temperature output registers and returns them
+    /// unconverted, skipping the floating point math, compensation and filtering performed by
+    /// [`Self::data_get`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<RawData, Error<B::Error>>`
+    ///     * `RawData`: Contains the raw pressure and temperature values.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    pub async fn data_get_raw(&mut self) -> Result<RawData, Error<B::Error>> {
+        let pressure = self.pressure_raw_get().await;
+        let pressure = self.recovery_check(pressure).await?;
+        let temperature = self.temperature_raw_get().await;
+        let temperature = self.recovery_check(temperature).await?;
+
+        Ok(RawData {
+            pressure,
+            temperature,
+        })
+    }
+
+    /// Retrieves the output data of the sensor converted into fixed point.
+    ///
+    /// This function reads the pressure and temperature output registers and converts them with
+    /// integer arithmetic only, see [`from_fs1260_to_hpa_fixed`], [`from_fs4000_to_hpa_fixed`] and
+    /// [`from_lsb_to_celsius_fixed`]. As with [`Self::data_get_raw`], no compensation or filtering
+    /// is applied. The full scale is the one applied with [`Self::mode_set`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FixedData, Error<B::Error>>`
+    ///     * `FixedData`: Contains the pressure and temperature values in `I16F16`.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    #[cfg(feature = "fixed")]
+    pub async fn data_get_fixed(&mut self) -> Result<FixedData, Error<B::Error>> {
+        let raw = self.data_get_raw().await?;
+
+        Ok(FixedData {
+            pressure_hpa: fixed::types::I16F16::from_bits(
+                raw.pressure >> self.md.fs.sensitivity().raw_to_fixed_shift(),
+            ),
+            temperature_deg_c: from_lsb_to_celsius_fixed(raw.temperature),
+        })
+    }
+
+    /// Retrieves the raw pressure and temperature in a single bus transaction.
+    ///
+    /// This is the low-latency read path intended for control loops running at the highest output
+    /// data rates (e.g. altitude hold at 200 Hz): PRESS_OUT_XL to TEMP_OUT_H are read in one
+    /// multi-byte access, no status register is polled and no floating point conversion is done.
+    /// Register address auto-increment must be enabled (see `Init::DrvRdy`).
+    ///
+    /// The worst-case bus time per call, excluding bus arbitration and driver latency, is about:
+    /// * I2C at 400 kHz: 72 clock cycles (address, register and 5 data bytes), 185 µs.
+    /// * I2C at 1 MHz: 75 µs.
+    /// * SPI at 10 MHz: 6 bytes, 5 µs.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<RawData, Error<B::Error>>`
+    ///     * `RawData`: Contains the raw pressure and temperature values.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the output registers.
+    pub async fn data_get_fast(&mut self) -> Result<RawData, Error<B::Error>> {
+        let mut buf = [0; 5];
+        let read = self
+            .read_from_register(Reg::PressOutXl as u8, &mut buf)
+            .await;
+        self.recovery_check(read).await?;
+        let [xl, l, h, t_l, t_h] = buf;
+
+        Ok(RawData {
+            pressure: decode::raw_pressure_from_bytes([xl, l, h]),
+            temperature: i16::from_le_bytes([t_l, t_h]),
+        })
+    }
+
+    /// Retrieves the raw pressure in a single bus transaction.
+    ///
+    /// This function behaves as [`Self::data_get_fast`], skipping the temperature bytes. The
+    /// worst-case bus time per call is about 140 µs with I2C at 400 kHz, 55 µs at 1 MHz and 4 µs
+    /// with SPI at 10 MHz.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i32, Error<B::Error>>`
+    ///     * `i32`: The raw pressure output value.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the output registers.
+    pub async fn pressure_get_fast(&mut self) -> Result<i32, Error<B::Error>> {
+        let mut buf = [0; 3];
+        let read = self
+            .read_from_register(Reg::PressOutXl as u8, &mut buf)
+            .await;
+        self.recovery_check(read).await?;
+        Ok(decode::raw_pressure_from_bytes(buf))
+    }
+
+    /// Configures the per-unit correction applied to converted pressure and temperature.
+    ///
+    /// When set, [`Self::data_get`] applies the correction to every sample right after the
+    /// conversion, so the characterization of a unit (e.g. a polynomial fitted at factory test)
+    /// does not have to be applied at every call site. FIFO samples carry no temperature and are
+    /// not corrected. The correction is applied in software and does not alter any device
+    /// register.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: The corrections to apply; [`UserCorrection::default`] disables them.
+    ///
+    /// ```ignore
+    /// sensor.user_correction_set(UserCorrection {
+    ///     pressure: Some(Correction::Polynomial([0.12, 0.9998, 2.1e-7, 0.0])),
+    ///     temperature: None,
+    /// });
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn user_correction_set(&mut self, val: UserCorrection) {
+        self.user_correction = val;
+    }
+
+    /// Retrieves the per-unit correction applied to converted pressure and temperature.
+    ///
+    /// # Returns
+    ///
+    /// * `UserCorrection`: The active corrections.
+    #[cfg(feature = "float")]
+    pub fn user_correction_get(&self) -> UserCorrection {
+        self.user_correction
+    }
+
+    /// Configures the temperature compensation applied to converted pressure.
+    ///
+    /// When set, [`Self::data_get`] corrects every pressure sample for the measured die
+    /// temperature using the supplied coefficient. Pass `None` to disable the compensation.
+    /// The compensation is applied in software and does not alter any device register.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: The compensation parameters, or `None` to report uncompensated pressure.
+    #[cfg(feature = "float")]
+    pub fn temp_compensation_set(&mut self, val: Option<TempCompensation>) {
+        self.temp_comp = val;
+    }
+
+    /// Retrieves the temperature compensation applied to converted pressure.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<TempCompensation>`: The active compensation, or `None` if disabled.
+    #[cfg(feature = "float")]
+    pub fn temp_compensation_get(&self) -> Option<TempCompensation> {
+        self.temp_comp
+    }
+
+    /// Enables or disables the spike-rejection stage of the data path.
+    ///
+    /// When set, [`Self::data_get`] feeds every converted pressure sample through the filter to
+    /// suppress single-sample glitches. Installing a filter discards any previous filter history.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: The spike filter to apply, or `None` to disable the stage.
+    #[cfg(feature = "float")]
+    pub fn spike_filter_set(&mut self, val: Option<SpikeFilter>) {
+        self.spike_filter = val;
+    }
+
+    /// Retrieves the spike-rejection stage of the data path.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<SpikeFilter>`: The active spike filter, or `None` if disabled.
+    #[cfg(feature = "float")]
+    pub fn spike_filter_get(&self) -> Option<SpikeFilter> {
+        self.spike_filter
+    }
+
+    /// Zeroes the pressure output at the current pressure.
+    ///
+    /// This function reads one sample with [`Self::data_get`] and stores its pressure, which is
+    /// then subtracted from the pressure reported by `data_get` and by the FIFO reads, for
+    /// gauge-style measurements. The tare is applied in software only: the RPDS offset of the
+    /// device is left untouched. A tare in place is cleared before the sample is read.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<f32, Error<B::Error>>`
+    ///     * `f32`: The captured tare pressure, in hPa.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    #[cfg(feature = "float")]
+    pub async fn tare(&mut self) -> Result<f32, Error<B::Error>> {
+        self.tare_hpa = None;
+        let tare_hpa = self.data_get().await?.pressure.hpa;
+        self.tare_hpa = Some(tare_hpa);
+        Ok(tare_hpa)
+    }
+
+    /// Removes the tare, so the pressure is reported as absolute again.
+    #[cfg(feature = "float")]
+    pub fn tare_clear(&mut self) {
+        self.tare_hpa = None;
+    }
+
+    /// Retrieves the tare pressure in place, in hPa.
+    #[cfg(feature = "float")]
+    pub fn tare_get(&self) -> Option<f32> {
+        self.tare_hpa
+    }
+
     ///
     /// This function reads the pressure data registers to obtain the raw pressure measurement value. The
     /// raw value is typically used for further processing or conversion into meaningful units such as
@@ -723,11 +1918,54 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         Ok(TempOut::read(self).await?.tout())
     }
 
+    /// Retrieves the temperature data from the sensor.
+    ///
+    /// This function reads only the temperature output registers, so the die temperature can be
+    /// sampled periodically, e.g. while the sensor runs in AH/QVAR mode, without decoding the
+    /// full `Data` structure.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Heat, Error<B::Error>>`
+    ///     * `Heat`: Contains the raw and converted temperature values.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the temperature data registers.
+    #[cfg(feature = "float")]
+    pub async fn temp_data_get(&mut self) -> Result<Heat, Error<B::Error>> {
+        let raw = self.temperature_raw_get().await;
+        let raw = self.recovery_check(raw).await?;
+        let deg_c = from_lsb_to_celsius(raw);
+
+        Ok(Heat { deg_c, raw })
+    }
+
+    /// Checks whether a new temperature sample is available.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, Error<B::Error>>`
+    ///     * `bool`: `true` if the temperature output holds a sample not read yet.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the status register.
+    pub async fn temp_data_ready(&mut self) -> Result<bool, Error<B::Error>> {
+        Ok(Status::read(self).await?.t_da() == PROPERTY_ENABLE)
+    }
+
     /// Retrieves AH/QVAR data from the sensor.
     ///
     /// This function reads the sensor registers to obtain AH/QVAR data, which is used for advanced
     /// sensing applications. The data is processed to provide both the raw and converted values,
-    /// allowing for detailed analysis and application-specific processing.
+    /// allowing for detailed analysis and application-specific processing. The millivolt value
+    /// uses the sensitivity set with [`Self::qvar_sensitivity_set`], followed by the gain of the
+    /// applied [`Calibration`].
     ///
     /// # Returns
     ///
@@ -740,14 +1978,89 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful reading of the AH/QVAR data registers.
+    #[cfg(feature = "qvar")]
     pub async fn ah_qvar_data_get(&mut self) -> Result<AhQvarData, Error<B::Error>> {
-        let raw = self.pressure_raw_get().await?;
+        let raw = self.pressure_raw_get().await;
+        let raw = self.recovery_check(raw).await?;
         let lsb = raw >> 8;
-        let mv = from_lsb_to_mv(lsb);
+        let mv = lsb as f32 / self.qvar_sensitivity * self.calibration.qvar_gain;
 
         Ok(AhQvarData { mv, lsb, raw })
     }
 
+    /// Configures the AH/QVAR conversion sensitivity.
+    ///
+    /// The default is the datasheet sensitivity, [`QVAR_SENSITIVITY_LSB_PER_MV`]. Setups with an
+    /// external front-end divider or a different electrode arrangement can set the effective
+    /// sensitivity of their chain so that [`Self::ah_qvar_data_get`] reports correct millivolts.
+    ///
+    /// # Parameters
+    ///
+    /// * `lsb_per_mv`: The number of LSB corresponding to one millivolt.
+    #[cfg(feature = "qvar")]
+    pub fn qvar_sensitivity_set(&mut self, lsb_per_mv: f32) {
+        self.qvar_sensitivity = lsb_per_mv;
+    }
+
+    /// Retrieves the AH/QVAR conversion sensitivity, in LSB/mV.
+    #[cfg(feature = "qvar")]
+    pub fn qvar_sensitivity_get(&self) -> f32 {
+        self.qvar_sensitivity
+    }
+
+    /// Retrieves the next sample of the managed AH/QVAR mode.
+    ///
+    /// This function returns AH/QVAR samples and, as planned by `schedule`, periodically turns the
+    /// AH/QVAR function off for a single pressure and temperature conversion, so both streams are
+    /// available without the application reconfiguring the device. Every toggle goes through
+    /// power-down and restarts sampling at the ODR applied with [`Self::mode_set`]. The function
+    /// must be called once per new sample (e.g. on data-ready), with AH/QVAR enabled and
+    /// interleaved mode disabled beforehand.
+    ///
+    /// # Parameters
+    ///
+    /// * `schedule`: A mutable reference to the schedule, kept across calls.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ManagedSample, Error<B::Error>>`
+    ///     * `ManagedSample`: The sample, labeled as AH/QVAR or pressure.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sample or toggling of the AH/QVAR function.
+    #[cfg(feature = "qvar")]
+    pub async fn ah_qvar_managed_get(
+        &mut self,
+        schedule: &mut QvarSchedule,
+    ) -> Result<ManagedSample, Error<B::Error>> {
+        if schedule.is_pressure_due() {
+            let data = self.data_get().await?;
+            self.ah_qvar_switch(true).await?;
+            schedule.pressure_sampled();
+            return Ok(ManagedSample::Pressure(data));
+        }
+
+        let data = self.ah_qvar_data_get().await?;
+        if schedule.qvar_sampled() {
+            self.ah_qvar_switch(false).await?;
+        }
+        Ok(ManagedSample::AhQvar(data))
+    }
+
+    #[cfg(feature = "qvar")]
+    async fn ah_qvar_switch(&mut self, enable: bool) -> Result<(), Error<B::Error>> {
+        let md = self.md;
+        self.stop_sampling().await?;
+        self.ah_qvar_en_set(enable).await?;
+        if !enable {
+            self.ah_qvar_disable().await?;
+        }
+        self.start_sampling(&md).await
+    }
+
     /// Configures the FIFO operation mode for the device.
     ///
     /// This function sets the FIFO (First-In, First-Out) operation mode, allowing the user to define
@@ -777,13 +2090,13 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         fifo_ctrl.set_f_mode((val.operation as u8) & 0x03);
         fifo_ctrl.set_trig_modes(((val.operation as u8) & 0x04) >> 2);
 
-        if val.watermark != 0 {
+        if val.watermark != Watermark::DISABLED {
             fifo_ctrl.set_stop_on_wtm(PROPERTY_ENABLE);
         } else {
             fifo_ctrl.set_stop_on_wtm(PROPERTY_DISABLE);
         }
 
-        fifo_wtm.set_wtm(val.watermark);
+        fifo_wtm.set_wtm(val.watermark.get());
 
         fifo_ctrl.write(self).await?;
         fifo_wtm.write(self).await
@@ -812,7 +2125,7 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
 
         let operation = Operation::try_from((fifo_ctrl.trig_modes() << 2) | fifo_ctrl.f_mode())
             .unwrap_or_default();
-        let watermark = fifo_wtm.wtm();
+        let watermark = Watermark::new(fifo_wtm.wtm()).unwrap_or_default();
 
         Ok(FifoMd {
             operation,
@@ -837,69 +2150,327 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful reading of the FIFO status register.
     pub async fn fifo_level_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ok(FifoStatus1::read(self).await?.fss())
+        let level = FifoStatus1::read(self).await?.fss();
+        self.fifo_stats.record(level);
+        Ok(level)
+    }
+
+    /// Returns the FIFO fill levels observed since the driver was created or the statistics
+    /// were last reset.
+    ///
+    /// Every [`Self::fifo_level_get`] is recorded, which includes the drains performed by
+    /// `fifo_data_get_vec` and by the polling and Embassy helpers, so the statistics reflect
+    /// the fill at drain time when the application reads the level before draining.
+    pub const fn fifo_stats_get(&self) -> FifoStats {
+        self.fifo_stats
+    }
+
+    /// Clears the FIFO fill statistics, e.g. after changing the watermark or the polling rate.
+    pub fn fifo_stats_reset(&mut self) {
+        self.fifo_stats = FifoStats::default();
+    }
+
+    /// Retrieves data from the FIFO buffer and processes it according to the sensor conversion
+    /// parameters.
+    ///
+    /// This function reads a specified number of samples from the FIFO buffer and processes each sample
+    /// based on the sensor conversion parameters applied with [`Self::mode_set`]. It supports both
+    /// pressure and AH_QVAR data retrieval, depending on the configuration.
+    ///
+    /// # Parameters
+    /// * `samp` - The number of samples to retrieve from the FIFO buffer. This must not exceed the
+    ///   length of the `data` buffer provided.
+    /// * `data`: A mutable slice of `FifoData` where the retrieved and processed data will
+    ///   be stored.
+    ///
+    /// # Returns
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `Ok`: The number of samples written at the beginning of `data`.
+    ///     * `Err`: Returns an error if the operation fails, such as when the number of samples
+    ///       requested exceeds the buffer size.
+    ///
+    /// # Errors
+    /// * `Error::Bus(B)`: Returned if a bus operation fails.
+    /// * `Error::BufferTooSmall`: Returned if the requested number of samples (`samp`) is
+    ///   greater than the length of the `data` buffer.
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get(
+        &mut self,
+        samp: u8,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        let md = self.md;
+        self.fifo_data_get_with(samp, &md, data).await
+    }
+
+    /// Retrieves data from the FIFO buffer as [`Self::fifo_data_get`], converting it with `md`
+    /// instead of the applied conversion parameters. The parameters kept by the driver, used by
+    /// the recovery policy, are left untouched.
+    #[cfg(feature = "float")]
+    pub(crate) async fn fifo_data_get_with(
+        &mut self,
+        samp: u8,
+        md: &Md,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        let Some(data) = data.get_mut(..samp as usize) else {
+            return Err(Error::BufferTooSmall);
+        };
+
+        for value in data.iter_mut() {
+            let raw = FifoDataOutPress::read(self).await;
+            let raw = self.recovery_check(raw).await?.fifo_p();
+            self.fifo_sample_convert(raw, md, value);
+        }
+        Ok(data.len())
+    }
+
+    /// Retrieves data from the FIFO buffer, keeping only one sample out of `decimation`.
+    ///
+    /// This function drains `samp` samples from the FIFO buffer like [`Self::fifo_data_get`], but
+    /// only the first sample of each group of `decimation` is converted and stored, so applications
+    /// oversampling for anti-aliasing do not process the samples they would discard. The
+    /// decimation phase restarts at every call: drain a multiple of `decimation` samples (e.g. by
+    /// setting the watermark accordingly) to get an evenly spaced output.
+    ///
+    /// # Parameters
+    /// * `samp`: The number of samples to drain from the FIFO buffer.
+    /// * `decimation`: The number of drained samples per stored sample; `0` and `1` keep every
+    ///   sample.
+    /// * `data`: A mutable slice of `FifoData` receiving the kept samples. It must hold at least
+    ///   `samp / decimation` samples, rounded up.
+    ///
+    /// # Returns
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `Ok`: The number of samples written at the beginning of `data`.
+    ///     * `Err`: Returns an error if the operation fails, such as when the kept samples
+    ///       exceed the buffer size.
+    ///
+    /// # Errors
+    /// * `Error::Bus(B)`: Returned if a bus operation fails.
+    /// * `Error::BufferTooSmall`: Returned if the kept samples do not fit in `data`; nothing is
+    ///   drained in that case.
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get_decimated(
+        &mut self,
+        samp: u8,
+        decimation: u8,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        let decimation = decimation.max(1);
+        let Some(data) = data.get_mut(..samp.div_ceil(decimation) as usize) else {
+            return Err(Error::BufferTooSmall);
+        };
+
+        let mut kept = data.iter_mut();
+        for index in 0..samp {
+            let raw = FifoDataOutPress::read(self).await;
+            let raw = self.recovery_check(raw).await?.fifo_p();
+            if index % decimation != 0 {
+                continue;
+            }
+            if let Some(value) = kept.next() {
+                self.fifo_sample_convert(raw, &self.md, value);
+            }
+        }
+        Ok(data.len())
+    }
+
+    #[cfg(feature = "float")]
+    fn fifo_sample_convert(&self, raw: i32, md: &Md, value: &mut FifoData) {
+        let timestamp_us = value.timestamp_us;
+        *value = decode::fifo_data_from_raw(raw, md);
+        value.timestamp_us = timestamp_us;
+
+        if !decode::is_ah_qvar_sample(raw, md) {
+            value.hpa = self.calibration.pressure_correct(value.hpa);
+            if let Some(tare_hpa) = self.tare_hpa {
+                value.hpa -= tare_hpa;
+            }
+        }
+    }
+
+    /// Retrieves data from the FIFO buffer, attaching reconstructed timestamps.
+    ///
+    /// This function behaves as [`Self::fifo_data_get`] and additionally fills the `timestamp_us`
+    /// field of each sample. The last retrieved sample is assumed to be acquired at `drain_us`,
+    /// and each older sample one ODR period earlier, so downstream processing gets an evenly
+    /// spaced time base even though the FIFO is drained in bursts. In one-shot mode every sample
+    /// gets `drain_us`.
+    ///
+    /// # Parameters
+    /// * `samp`: The number of samples to retrieve from the FIFO buffer.
+    /// * `drain_us`: The time of the drain, in microseconds, on the application time base.
+    /// * `data`: A mutable slice of `FifoData` where the retrieved and processed data will
+    ///   be stored.
+    ///
+    /// # Returns
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `Ok`: The number of samples written at the beginning of `data`.
+    ///     * `Err`: Returns an error if the operation fails, such as when the number of samples
+    ///       requested exceeds the buffer size.
+    ///
+    /// # Errors
+    /// * `Error::Bus(B)`: Returned if a bus operation fails.
+    /// * `Error::BufferTooSmall`: Returned if the requested number of samples (`samp`) is
+    ///   greater than the length of the `data` buffer.
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get_timestamped(
+        &mut self,
+        samp: u8,
+        drain_us: u64,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        let samp = self.fifo_data_get(samp, data).await?;
+
+        let period_us = u64::from(self.md.odr.period_us().unwrap_or(0));
+        for (age, value) in data.iter_mut().take(samp).rev().enumerate() {
+            value.timestamp_us = drain_us.saturating_sub(age as u64 * period_us);
+        }
+        Ok(samp)
+    }
+
+    /// Retrieves data from the FIFO buffer, stamping the samples with a time source.
+    ///
+    /// This function behaves as [`Self::fifo_data_get_timestamped`], with the drain time read
+    /// from `clock` right before the FIFO is read.
+    ///
+    /// # Parameters
+    /// * `samp`: The number of samples to retrieve from the FIFO buffer.
+    /// * `clock`: The time source, in microseconds.
+    /// * `data`: A mutable slice of `FifoData` where the retrieved and processed data will
+    ///   be stored.
+    ///
+    /// # Returns
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `Ok`: The number of samples written at the beginning of `data`.
+    ///     * `Err`: Returns an error if the operation fails, such as when the number of samples
+    ///       requested exceeds the buffer size.
+    ///
+    /// # Errors
+    /// * `Error::Bus(B)`: Returned if a bus operation fails.
+    /// * `Error::BufferTooSmall`: Returned if the requested number of samples (`samp`) is
+    ///   greater than the length of the `data` buffer.
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get_clocked<T: Timestamp>(
+        &mut self,
+        samp: u8,
+        clock: &mut T,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        let drain_us = clock.now_us();
+        self.fifo_data_get_timestamped(samp, drain_us, data).await
+    }
+
+    /// Retrieves data from the FIFO buffer, attaching sequence numbers and the overrun flag.
+    ///
+    /// This function behaves as [`Self::fifo_data_get`], numbering the samples with the same
+    /// sequence counter as [`Self::data_get_tagged`]. The `FIFO_STATUS2` register is read first:
+    /// if the FIFO overflowed, the oldest samples were overwritten and the first retrieved sample
+    /// is flagged as overrun. FIFO samples are never stale.
+    ///
+    /// # Parameters
+    /// * `samp`: The number of samples to retrieve from the FIFO buffer.
+    /// * `data`: A mutable slice of `Sample<FifoData>` where the retrieved and processed data
+    ///   will be stored.
+    ///
+    /// # Returns
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `Ok`: The number of samples written at the beginning of `data`.
+    ///     * `Err`: Returns an error if the operation fails, such as when the number of samples
+    ///       requested exceeds the buffer size.
+    ///
+    /// # Errors
+    /// * `Error::Bus(B)`: Returned if a bus operation fails.
+    /// * `Error::BufferTooSmall`: Returned if the requested number of samples (`samp`) is
+    ///   greater than the length of the `data` buffer.
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get_tagged(
+        &mut self,
+        samp: u8,
+        data: &mut [Sample<FifoData>],
+    ) -> Result<usize, Error<B::Error>> {
+        let Some(data) = data.get_mut(..samp as usize) else {
+            return Err(Error::BufferTooSmall);
+        };
+
+        let mut overrun = FifoStatus2::read(self).await?.fifo_ovr_ia() == PROPERTY_ENABLE;
+        for sample in data.iter_mut() {
+            let raw = FifoDataOutPress::read(self).await;
+            let raw = self.recovery_check(raw).await?.fifo_p();
+            self.fifo_sample_convert(raw, &self.md, &mut sample.data);
+            self.seq = self.seq.wrapping_add(1);
+            sample.seq = self.seq;
+            sample.overrun = overrun;
+            sample.stale = false;
+            overrun = false;
+        }
+        Ok(data.len())
     }
 
-    /// Retrieves data from the FIFO buffer and processes it according to the sensor conversion
-    /// parameters.
+    /// Retrieves raw data from the FIFO buffer.
     ///
-    /// This function reads a specified number of samples from the FIFO buffer and processes each sample
-    /// based on the sensor conversion parameters provided. It supports both pressure and AH_QVAR data
-    /// retrieval, depending on the configuration.
+    /// This function reads the specified number of samples from the FIFO buffer and stores the raw
+    /// values, without converting them to hPa.
     ///
     /// # Parameters
-    /// * `samp` - The number of samples to retrieve from the FIFO buffer. This must not exceed the
-    ///   length of the `data` buffer provided.
-    /// * `md`: A reference to `Md`, which contains the sensor conversion parameters,
-    ///   including the full-scale range and interleaved mode settings.
-    /// * `data`: A mutable slice of `FifoData` where the retrieved and processed data will
-    ///   be stored.
+    /// * `samp`: The number of samples to retrieve from the FIFO buffer.
+    /// * `data`: A mutable slice receiving the raw samples.
     ///
     /// # Returns
-    /// * `Result<(), Error<B::Error>>`
-    ///     * `Ok`: Indicates successful data retrieval and processing.
+    /// * `Result<usize, Error<B::Error>>`
+    ///     * `Ok`: The number of samples written at the beginning of `data`.
     ///     * `Err`: Returns an error if the operation fails, such as when the number of samples
     ///       requested exceeds the buffer size.
     ///
     /// # Errors
     /// * `Error::Bus(B)`: Returned if a bus operation fails.
-    /// * `Error::FifoSampGraterThanBuff`: Returned if the requested number of samples (`samp`) is
+    /// * `Error::BufferTooSmall`: Returned if the requested number of samples (`samp`) is
     ///   greater than the length of the `data` buffer.
-    pub async fn fifo_data_get(
+    pub async fn fifo_data_get_raw(
         &mut self,
         samp: u8,
-        md: &Md,
-        data: &mut [FifoData],
-    ) -> Result<(), Error<B::Error>> {
-        if samp > data.len() as u8 {
-            return Err(Error::FifoSampGraterThanBuff);
+        data: &mut [i32],
+    ) -> Result<usize, Error<B::Error>> {
+        let Some(data) = data.get_mut(..samp as usize) else {
+            return Err(Error::BufferTooSmall);
+        };
+
+        for value in data.iter_mut() {
+            let raw = FifoDataOutPress::read(self).await;
+            *value = self.recovery_check(raw).await?.fifo_p();
         }
+        Ok(data.len())
+    }
 
-        for value in data.iter_mut().take(samp as usize) {
-            value.raw = FifoDataOutPress::read(self).await?.fifo_p();
-
-            if md.interleaved_mode == PROPERTY_ENABLE {
-                if (value.raw & 0x1) == 0 {
-                    // Data is a pressure sample
-                    value.hpa = match md.fs {
-                        Fs::_1260hpa => from_fs1260_to_hpa(value.raw),
-                        Fs::_4060hpa => from_fs4000_to_hpa(value.raw),
-                    };
-                    value.lsb = 0;
-                } else {
-                    // Data is an AH_QVAR sample
-                    value.lsb = value.raw >> 8;
-                    value.hpa = 0.;
-                }
-            } else {
-                value.hpa = match md.fs {
-                    Fs::_1260hpa => from_fs1260_to_hpa(value.raw),
-                    Fs::_4060hpa => from_fs4000_to_hpa(value.raw),
-                };
-                value.lsb = 0;
-            }
-        }
-        Ok(())
+    /// Retrieves the samples currently stored in the FIFO.
+    ///
+    /// This function reads the FIFO level and then up to `N` samples, returning only the valid
+    /// ones. Samples exceeding the capacity are left in the FIFO for the next call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<heapless::Vec<FifoData, N>, Error<B::Error>>`
+    ///     * `Ok`: The retrieved samples, in FIFO order.
+    ///     * `Err`: Returns an error if the operation fails.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Returned if a bus operation fails.
+    #[cfg(feature = "heapless")]
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get_vec<const N: usize>(
+        &mut self,
+    ) -> Result<heapless::Vec<FifoData, N>, Error<B::Error>> {
+        let level = self.fifo_level_get().await?;
+        let samp = N.min(level as usize);
+
+        let mut data = heapless::Vec::new();
+        // `samp` never exceeds the capacity
+        let _ = data.resize_default(samp);
+        self.fifo_data_get(samp as u8, &mut data).await?;
+
+        Ok(data)
     }
 
     /// Configures the hardware signal settings for the interrupt pins.
@@ -909,8 +2480,8 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// # Parameters
     ///
-    /// * `int_latched`: Contains the desired hardware signal settings for
-    ///   the interrupt pins.
+    /// * `int_latched`: `true` to latch interrupt requests into the `INT_SOURCE` register,
+    ///   `false` for pulsed interrupt signals.
     ///
     /// # Returns
     ///
@@ -922,9 +2493,9 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful writing of the interrupt configuration settings.
-    pub async fn interrupt_mode_set(&mut self, int_latched: u8) -> Result<(), Error<B::Error>> {
+    pub async fn interrupt_mode_set(&mut self, int_latched: bool) -> Result<(), Error<B::Error>> {
         let mut interrupt_cfg = InterruptCfg::read(self).await?;
-        interrupt_cfg.set_lir(int_latched);
+        interrupt_cfg.set_lir(int_latched as u8);
         interrupt_cfg.write(self).await
     }
 
@@ -935,16 +2506,87 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// # Returns
     ///
-    /// * `Result<IntMode, Error<B::Error>>`
-    ///     * `u8`: Contains the current status of latched interrupt signals.
+    /// * `Result<bool, Error<B::Error>>`
+    ///     * `bool`: `true` when interrupt signals are latched.
     ///     * `Err`: Returns an error if the operation fails due to a bus communication error.
     ///
     /// # Errors
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful reading of the interrupt configuration settings.
-    pub async fn interrupt_mode_get(&mut self) -> Result<u8, Error<B::Error>> {
-        Ok(InterruptCfg::read(self).await?.lir())
+    pub async fn interrupt_mode_get(&mut self) -> Result<bool, Error<B::Error>> {
+        Ok(InterruptCfg::read(self).await?.lir() == PROPERTY_ENABLE)
+    }
+
+    /// Configures the threshold interrupts, their latching and their reference in one write.
+    ///
+    /// This function programs the whole INTERRUPT_CFG register from typed settings, so the
+    /// threshold-event features are usable without handling the register bitfield. The threshold
+    /// value itself is set with [`Self::int_on_threshold_mode_set`].
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: A reference to `IntConfig`, which contains the threshold events to enable, the
+    ///   latching of the interrupt requests and the reference pressure of the comparison.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok`: Indicates successful configuration of the interrupts.
+    ///     * `Err`: Returns an error if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the interrupt configuration.
+    pub async fn int_config_set(&mut self, val: &IntConfig) -> Result<(), Error<B::Error>> {
+        let mut interrupt_cfg = InterruptCfg::new();
+
+        interrupt_cfg.set_phe(val.over_th as u8);
+        interrupt_cfg.set_ple(val.under_th as u8);
+        interrupt_cfg.set_lir(val.latched as u8);
+        match val.reference {
+            IntReference::Absolute => {
+                interrupt_cfg.set_reset_az(PROPERTY_ENABLE);
+                interrupt_cfg.set_reset_arp(PROPERTY_ENABLE);
+            }
+            IntReference::AutoZero => interrupt_cfg.set_autozero(PROPERTY_ENABLE),
+            IntReference::AutoRefP => interrupt_cfg.set_autorefp(PROPERTY_ENABLE),
+        }
+
+        interrupt_cfg.write(self).await
+    }
+
+    /// Retrieves the complete interrupt configuration.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<IntConfig, Error<B::Error>>`
+    ///     * `IntConfig`: The threshold events enabled, the latching of the interrupt requests and
+    ///       the reference pressure of the comparison.
+    ///     * `Err`: Returns an error if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the interrupt configuration.
+    pub async fn int_config_get(&mut self) -> Result<IntConfig, Error<B::Error>> {
+        let interrupt_cfg = InterruptCfg::read(self).await?;
+
+        let reference = if interrupt_cfg.autozero() == PROPERTY_ENABLE {
+            IntReference::AutoZero
+        } else if interrupt_cfg.autorefp() == PROPERTY_ENABLE {
+            IntReference::AutoRefP
+        } else {
+            IntReference::Absolute
+        };
+
+        Ok(IntConfig {
+            over_th: interrupt_cfg.phe() == PROPERTY_ENABLE,
+            under_th: interrupt_cfg.ple() == PROPERTY_ENABLE,
+            latched: interrupt_cfg.lir() == PROPERTY_ENABLE,
+            reference,
+        })
     }
 
     /// Disables the AH/QVAR function on the device.
@@ -963,6 +2605,7 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     ///
     /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
     ///   successful writing of the disable command to the register.
+    #[cfg(feature = "qvar")]
     pub async fn ah_qvar_disable(&mut self) -> Result<(), Error<B::Error>> {
         self.write_to_register(Reg::AnalogicHubDisable as u8, &[PROPERTY_DISABLE])
             .await?;
@@ -997,8 +2640,8 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let mut interrupt_cfg = InterruptCfg::read(self).await?;
         let mut ths_p = ThsP::read(self).await?;
 
-        interrupt_cfg.set_phe(val.over_th);
-        interrupt_cfg.set_ple(val.under_th);
+        interrupt_cfg.set_phe(val.over_th as u8);
+        interrupt_cfg.set_ple(val.under_th as u8);
 
         ths_p.set_ths(val.threshold);
 
@@ -1029,8 +2672,8 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let interrupt_cfg = InterruptCfg::read(self).await?;
         let ths_p = ThsP::read(self).await?;
 
-        let over_th = interrupt_cfg.phe();
-        let under_th = interrupt_cfg.ple();
+        let over_th = interrupt_cfg.phe() == PROPERTY_ENABLE;
+        let under_th = interrupt_cfg.ple() == PROPERTY_ENABLE;
         let threshold = ths_p.ths();
 
         Ok(IntThMd {
@@ -1040,6 +2683,157 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         })
     }
 
+    /// Configures a pressure alarm with a hysteresis band.
+    ///
+    /// This function captures the current pressure as reference for the interrupt logic only
+    /// (the output data is left untouched) and arms the threshold interrupt for the activation
+    /// of `val`, see [`ThresholdHysteresis`]. The threshold resolution follows the full scale
+    /// applied with [`Self::mode_set`].
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: A reference to the alarm to configure.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: Indicates successful configuration of the alarm.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the threshold and reference settings.
+    #[cfg(feature = "float")]
+    pub async fn threshold_hysteresis_set(
+        &mut self,
+        val: &ThresholdHysteresis,
+    ) -> Result<(), Error<B::Error>> {
+        self.reference_mode_set(&RefMd {
+            apply_ref: ApplyRef::OnlyInterrupt,
+            get_ref: true,
+        })
+        .await?;
+        self.int_on_threshold_mode_set(&val.int_th_md(self.md.fs))
+            .await
+    }
+
+    /// Updates a pressure alarm configured with [`Self::threshold_hysteresis_set`].
+    ///
+    /// This function compares `hpa` with the captured reference pressure, updates the alarm state
+    /// and re-arms or disarms the threshold interrupt when the state changes. It is meant to be
+    /// called on the threshold interrupt and on every sample while the alarm is active.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: A mutable reference to the alarm.
+    /// * `hpa`: The latest converted pressure, in hPa.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, Error<B::Error>>`
+    ///     * `bool`: `true` while the alarm is raised.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the reference or writing of the threshold settings.
+    #[cfg(feature = "float")]
+    pub async fn threshold_hysteresis_update(
+        &mut self,
+        val: &mut ThresholdHysteresis,
+        hpa: f32,
+    ) -> Result<bool, Error<B::Error>> {
+        let reference = RefP::read(self).await?.refp().cast_signed();
+        let fs = self.md.fs;
+        let reference_hpa = fs.sensitivity().reg16_to_hpa(reference.into());
+
+        if val.update(hpa - reference_hpa) {
+            self.int_on_threshold_mode_set(&val.int_th_md(fs)).await?;
+        }
+        Ok(val.is_active())
+    }
+
+    /// Captures the current pressure as zero reference for differential measurements.
+    ///
+    /// This function enables the AUTOZERO function with the reference applied to both the output
+    /// and the interrupt logic: from the next conversion on, the pressure output holds the
+    /// difference from the captured reference, which can be read with [`Self::differential_get`].
+    /// Calling it again captures a new reference; [`Self::differential_reset`] restores absolute
+    /// readings.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: Indicates the AUTOZERO function was successfully enabled.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the reference configuration.
+    pub async fn differential_zero(&mut self) -> Result<(), Error<B::Error>> {
+        self.reference_mode_set(&RefMd {
+            apply_ref: ApplyRef::RstRefs,
+            get_ref: false,
+        })
+        .await?;
+        self.reference_mode_set(&RefMd {
+            apply_ref: ApplyRef::OutAndInterrupt,
+            get_ref: true,
+        })
+        .await
+    }
+
+    /// Retrieves the pressure difference from the reference captured by [`Self::differential_zero`].
+    ///
+    /// The reading is converted according to the full scale applied with [`Self::mode_set`] and
+    /// reported in both hPa and Pa. Temperature compensation, calibration and spike filtering are
+    /// not applied, since their offsets are already cancelled by the reference.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DiffPressure, Error<B::Error>>`
+    ///     * `DiffPressure`: The signed pressure difference from the reference.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the pressure output.
+    #[cfg(feature = "float")]
+    pub async fn differential_get(&mut self) -> Result<DiffPressure, Error<B::Error>> {
+        let raw = self.pressure_raw_get().await?;
+        let hpa = from_lsb_to_hpa(self.md.fs, raw);
+
+        Ok(DiffPressure {
+            hpa,
+            pa: hpa * 100.0,
+            raw,
+        })
+    }
+
+    /// Disables the differential measurement, returning to absolute pressure readings.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: Indicates the AUTOZERO function was successfully disabled.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the reference configuration.
+    pub async fn differential_reset(&mut self) -> Result<(), Error<B::Error>> {
+        self.reference_mode_set(&RefMd {
+            apply_ref: ApplyRef::RstRefs,
+            get_ref: false,
+        })
+        .await
+    }
+
     /// Configures the reference mode settings for wake-up and wake-up-to-sleep functionality.
     ///
     /// This function sets the reference mode parameters, which are used to manage how the device
@@ -1063,7 +2857,7 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     pub async fn reference_mode_set(&mut self, val: &RefMd) -> Result<(), Error<B::Error>> {
         let mut interrupt_cfg = InterruptCfg::read(self).await?;
 
-        interrupt_cfg.set_autozero(val.get_ref);
+        interrupt_cfg.set_autozero(val.get_ref as u8);
         interrupt_cfg.set_autorefp((val.apply_ref as u8) & 0x01);
 
         interrupt_cfg.set_reset_az(((val.apply_ref as u8) & 0x02) >> 1);
@@ -1096,7 +2890,7 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
         let val = (interrupt_cfg.reset_az() << 1) | interrupt_cfg.autorefp();
 
         let apply_ref = ApplyRef::try_from(val).unwrap_or_default();
-        let get_ref = interrupt_cfg.autozero();
+        let get_ref = interrupt_cfg.autozero() == PROPERTY_ENABLE;
 
         Ok(RefMd { apply_ref, get_ref })
     }
@@ -1141,6 +2935,194 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
     pub async fn opc_get(&mut self) -> Result<i16, Error<B::Error>> {
         Ok(Rpds::read(self).await?.rpds())
     }
+
+    /// Calibrates the pressure offset at a known reference pressure.
+    ///
+    /// This function automates the One-Point Calibration: it averages `n_samples` conversions
+    /// taken while the device is exposed to `known_hpa`, then writes the RPDS offset cancelling
+    /// the difference. The offset already in place is accounted for, so the routine can be run
+    /// again to refine a previous calibration. The conversion parameters are those applied with
+    /// [`Self::mode_set`]: in one-shot mode the conversions are triggered with
+    /// [`Self::trigger_sw`], otherwise the device is already sampling. The software calibration
+    /// and compensation stages are not involved.
+    ///
+    /// The RPDS resolution is 1/16 hPa with the 1260 hPa full scale and 1/8 hPa with the
+    /// 4060 hPa full scale.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay`: The timing peripheral used while waiting for each conversion.
+    /// * `known_hpa`: The reference pressure applied to the device, in hPa.
+    /// * `n_samples`: The number of conversions to average; `0` is treated as `1`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i16, Error<B::Error>>`
+    ///     * `i16`: The RPDS offset written to the device.
+    ///     * `Err`: Returns an error if the operation fails or a conversion times out.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device.
+    /// * `Error::Timeout`: Occurs if a conversion does not complete within twice its period.
+    #[cfg(feature = "float")]
+    pub async fn calibrate_offset<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        known_hpa: f32,
+        n_samples: u16,
+    ) -> Result<i16, Error<B::Error>> {
+        let md = self.md;
+        let current = self.opc_get().await?;
+        let timeout_us = md
+            .odr
+            .period_us()
+            .unwrap_or(md.avg.conversion_time_us())
+            .saturating_mul(2);
+
+        let n_samples = n_samples.max(1);
+        let mut sum = 0i64;
+        for _ in 0..n_samples {
+            self.trigger_sw().await?;
+            self.data_ready_wait(delay, timeout_us).await?;
+            sum += i64::from(self.pressure_raw_get().await?);
+        }
+        let mean = (sum / i64::from(n_samples)) as i32;
+        let measured_hpa = decode::hpa_from_raw(mean, md.fs);
+
+        let offset =
+            f32::from(current) + md.fs.sensitivity().hpa_to_reg16(measured_hpa - known_hpa);
+        // Round half away from zero; the cast saturates to the RPDS range.
+        let rpds = (offset + 0.5f32.copysign(offset)) as i16;
+
+        self.opc_set(rpds).await?;
+        Ok(rpds)
+    }
+
+    /// Calibrates the pressure gain and offset from two reference points.
+    ///
+    /// This function fits the software gain and offset of the [`Calibration`] in place, so that
+    /// the two measured pressures map to their references in subsequent conversions. The
+    /// measurements are the pressures reported by [`Self::data_get`] at each reference, without
+    /// tare; the points are best taken near the ends of the pressure range of the application.
+    /// The RPDS offset is left untouched.
+    ///
+    /// # Parameters
+    ///
+    /// * `low`: The pressure measured at the lower reference.
+    /// * `high`: The pressure measured at the higher reference.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Calibration>`: The calibration now in place, e.g. to be stored, or `None` if the
+    ///   points do not define an increasing line; the calibration is unchanged in that case.
+    #[cfg(feature = "float")]
+    pub fn calibrate_two_point(
+        &mut self,
+        low: CalibrationPoint,
+        high: CalibrationPoint,
+    ) -> Option<Calibration> {
+        let calibration = self.calibration.two_point(low, high)?;
+        self.calibration = calibration;
+        Some(calibration)
+    }
+
+    /// Retrieves the complete device configuration.
+    ///
+    /// This function reads back the conversion mode, FIFO, interrupt, threshold, reference,
+    /// offset and interface settings, so they can later be written again with
+    /// [`Self::config_restore`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DeviceConfig, Error<B::Error>>`
+    ///     * `DeviceConfig`: Contains the current device configuration.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the configuration.
+    pub async fn config_snapshot(&mut self) -> Result<DeviceConfig, Error<B::Error>> {
+        Ok(DeviceConfig {
+            bus_mode: self.bus_mode_get().await?,
+            pin_conf: self.pin_conf_get().await?,
+            md: self.mode_get().await?,
+            fifo: self.fifo_mode_get().await?,
+            int_latched: self.interrupt_mode_get().await?,
+            int_th: self.int_on_threshold_mode_get().await?,
+            reference: self.reference_mode_get().await?,
+            opc: self.opc_get().await?,
+            #[cfg(feature = "qvar")]
+            ah_qvar_en: self.ah_qvar_en_get().await?,
+        })
+    }
+
+    /// Restores a device configuration.
+    ///
+    /// This function writes back a configuration obtained with [`Self::config_snapshot`]. The
+    /// conversion mode is applied last, so that the device only starts sampling once the FIFO and
+    /// interrupt settings are in place.
+    ///
+    /// # Parameters
+    ///
+    /// * `val`: A reference to the `DeviceConfig` to apply.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error<B::Error>>`
+    ///     * `Ok(())`: Indicates successful configuration of the device.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the configuration.
+    pub async fn config_restore(&mut self, val: &DeviceConfig) -> Result<(), Error<B::Error>> {
+        self.bus_mode_set(val.bus_mode).await?;
+        self.pin_conf_set(&val.pin_conf).await?;
+        self.interrupt_mode_set(val.int_latched).await?;
+        self.int_on_threshold_mode_set(&val.int_th).await?;
+        self.opc_set(val.opc).await?;
+        self.reference_mode_set(&val.reference).await?;
+        self.fifo_mode_set(&val.fifo).await?;
+        #[cfg(feature = "qvar")]
+        self.ah_qvar_en_set(val.ah_qvar_en).await?;
+        self.mode_set(&val.md).await
+    }
+
+    /// Reads the configuration registers for a diagnostics dump.
+    ///
+    /// Unlike [`Self::config_snapshot`], this function returns the register values as read, with
+    /// the reserved bits, so that the `Debug` or `defmt` output of the result shows the complete
+    /// state of the device.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DeviceConfigDebug, Error<B::Error>>`
+    ///     * `DeviceConfigDebug`: Contains the configuration registers.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the registers.
+    pub async fn config_debug_get(&mut self) -> Result<DeviceConfigDebug, Error<B::Error>> {
+        Ok(DeviceConfigDebug {
+            interrupt_cfg: InterruptCfg::read(self).await?,
+            ths_p: ThsP::read(self).await?,
+            if_ctrl: IfCtrl::read(self).await?,
+            who_am_i: WhoAmI::read(self).await?,
+            ctrl_reg1: CtrlReg1::read(self).await?,
+            ctrl_reg2: CtrlReg2::read(self).await?,
+            ctrl_reg3: CtrlReg3::read(self).await?,
+            fifo_ctrl: FifoCtrl::read(self).await?,
+            fifo_wtm: FifoWtm::read(self).await?,
+            ref_p: RefP::read(self).await?,
+            i3c_if_ctrl: I3cIfCtrl::read(self).await?,
+            rpds: Rpds::read(self).await?,
+        })
+    }
 }
 
 /// Converts raw pressure data from the full-scale 1260 hPa setting to hectopascals.
@@ -1150,9 +3132,10 @@ impl<B: BusOperation, T: DelayNs> Ilps22qs<B, T, OnState> {
 ///
 /// # Returns
 /// * `f32`: The pressure value in hectopascals.
+#[cfg(feature = "float")]
 #[bisync]
 pub fn from_fs1260_to_hpa(lsb: i32) -> f32 {
-    (lsb as f32) / 1048576.0
+    Fs::_1260hpa.sensitivity().raw_to_hpa(lsb)
 }
 
 /// Converts raw pressure data from the full-scale 4000 hPa setting to hectopascals.
@@ -1162,9 +3145,27 @@ pub fn from_fs1260_to_hpa(lsb: i32) -> f32 {
 ///
 /// # Returns
 /// * `f32`: The pressure value in hectopascals.
+#[cfg(feature = "float")]
 #[bisync]
 pub fn from_fs4000_to_hpa(lsb: i32) -> f32 {
-    (lsb as f32) / 524288.0
+    Fs::_4060hpa.sensitivity().raw_to_hpa(lsb)
+}
+
+/// Converts raw pressure data to hectopascals for the given full scale.
+///
+/// This is the conversion used by the driver, exposed so that host tools processing raw
+/// telemetry (e.g. `Pressure::raw` or FIFO dumps) obtain exactly the same values.
+///
+/// # Parameters
+/// * `fs`: The full scale the data was acquired with.
+/// * `lsb`: The raw pressure data as a 32-bit integer.
+///
+/// # Returns
+/// * `f32`: The pressure value in hectopascals.
+#[cfg(feature = "float")]
+#[bisync]
+pub fn from_lsb_to_hpa(fs: Fs, lsb: i32) -> f32 {
+    fs.sensitivity().raw_to_hpa(lsb)
 }
 
 /// Converts raw temperature data to degrees Celsius.
@@ -1174,9 +3175,52 @@ pub fn from_fs4000_to_hpa(lsb: i32) -> f32 {
 ///
 /// # Returns
 /// * `f32`: The temperature value in degrees Celsius.
+#[cfg(feature = "float")]
 #[bisync]
 pub fn from_lsb_to_celsius(lsb: i16) -> f32 {
-    (lsb as f32) / 100.0
+    (lsb as f32) / TEMPERATURE_SENSITIVITY_LSB_PER_DEG_C as f32
+}
+
+/// Converts raw pressure data from the full-scale 1260 hPa setting to fixed-point hectopascals.
+///
+/// # Parameters
+///
+/// * `lsb`: The raw pressure data value.
+///
+/// # Returns
+///
+/// * `I16F16`: The pressure in hectopascals.
+#[cfg(feature = "fixed")]
+pub fn from_fs1260_to_hpa_fixed(lsb: i32) -> fixed::types::I16F16 {
+    fixed::types::I16F16::from_bits(lsb >> Fs::_1260hpa.sensitivity().raw_to_fixed_shift())
+}
+
+/// Converts raw pressure data from the full-scale 4000 hPa setting to fixed-point hectopascals.
+///
+/// # Parameters
+///
+/// * `lsb`: The raw pressure data value.
+///
+/// # Returns
+///
+/// * `I16F16`: The pressure in hectopascals.
+#[cfg(feature = "fixed")]
+pub fn from_fs4000_to_hpa_fixed(lsb: i32) -> fixed::types::I16F16 {
+    fixed::types::I16F16::from_bits(lsb >> Fs::_4060hpa.sensitivity().raw_to_fixed_shift())
+}
+
+/// Converts raw temperature data to fixed-point degrees Celsius.
+///
+/// # Parameters
+///
+/// * `lsb`: The raw temperature data value.
+///
+/// # Returns
+///
+/// * `I16F16`: The temperature in degrees Celsius.
+#[cfg(feature = "fixed")]
+pub fn from_lsb_to_celsius_fixed(lsb: i16) -> fixed::types::I16F16 {
+    fixed::types::I16F16::from_num(lsb) / TEMPERATURE_SENSITIVITY_LSB_PER_DEG_C as i32
 }
 
 /// Converts raw AH/QVAR data to millivolts.
@@ -1185,10 +3229,11 @@ pub fn from_lsb_to_celsius(lsb: i16) -> f32 {
 /// * `lsb`: The raw AH/QVAR data as a 32-bit integer.
 ///
 /// # Returns
-/// * `f32`: The voltage value in millivolts.
+/// * `f32`: The voltage value in millivolts, using the datasheet sensitivity.
 #[bisync]
+#[cfg(feature = "qvar")]
 pub fn from_lsb_to_mv(lsb: i32) -> f32 {
-    (lsb as f32) / 438000.0
+    (lsb as f32) / QVAR_SENSITIVITY_LSB_PER_MV
 }
 
 /// Represents the I2C address for the device.
@@ -1201,10 +3246,42 @@ pub enum I2CAddress {
     SD0High = 0x5d,
 }
 
+/// Datasheet AH/QVAR sensitivity, in LSB/mV.
+#[bisync]
+#[cfg(feature = "qvar")]
+pub const QVAR_SENSITIVITY_LSB_PER_MV: f32 = 438000.0;
+
 /// Device Who am I.
 #[bisync]
 pub const ILPS22QS_ID: u8 = 0xB4;
 
+/// Number of samples held by the FIFO.
+#[bisync]
+pub const FIFO_DEPTH: usize = 128;
+
+/// Datasheet pressure sensitivity at the 1260 hPa full scale, in LSB/hPa of the 24-bit output.
+///
+/// The raw values returned by the driver are left-aligned on 32 bits, so they count
+/// `256 * PRESSURE_SENSITIVITY_FS1260_LSB_PER_HPA` per hPa.
+#[bisync]
+pub const PRESSURE_SENSITIVITY_FS1260_LSB_PER_HPA: u32 = 4096;
+
+/// Datasheet pressure sensitivity at the 4060 hPa full scale, in LSB/hPa of the 24-bit output.
+#[bisync]
+pub const PRESSURE_SENSITIVITY_FS4060_LSB_PER_HPA: u32 = 2048;
+
+/// Datasheet temperature sensitivity, in LSB/°C.
+#[bisync]
+pub const TEMPERATURE_SENSITIVITY_LSB_PER_DEG_C: u32 = 100;
+
+/// Duration of the boot procedure reloading the trimming parameters, in milliseconds.
+#[bisync]
+pub const BOOT_TIME_MS: u32 = 10;
+
+/// Interval between two polls of the software reset completion, in microseconds.
+#[bisync]
+pub const SW_RESET_POLL_US: u32 = 50;
+
 #[bisync]
 pub const PROPERTY_ENABLE: u8 = 1;
 #[bisync]
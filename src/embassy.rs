@@ -0,0 +1,108 @@
+//! Helpers integrating the asynchronous driver with the embassy framework.
+
+use super::{BusOperation, Error, Ilps22qs, PROPERTY_ENABLE, RegisterOperation, prelude::*};
+
+use core::convert::Infallible;
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::channel::Sender;
+use embassy_time::{Duration, Ticker, Timer};
+
+/// Samples the sensor at the configured output data rate and pushes every reading into a channel.
+///
+/// This function encapsulates the periodic polling loop of the embassy examples: an
+/// `embassy_time::Ticker` running at the output data rate applied with [`Ilps22qs::mode_set`]
+/// paces the reads, and each `Data` returned by [`Ilps22qs::data_get`] is sent to `sender`. The
+/// sensor must already be configured. With `Odr::OneShot`, a conversion is triggered once per
+/// second and read back when ready.
+///
+/// The function runs forever and is meant to be the body of an embassy task.
+///
+/// # Parameters
+///
+/// * `sensor`: The configured sensor.
+/// * `sender`: The channel endpoint receiving the samples.
+///
+/// # Errors
+///
+/// * `Error::Bus(B)`: Returned, ending the loop, if a bus operation fails.
+pub async fn sampling_task<B, M, const N: usize>(
+    sensor: &mut Ilps22qs<B, OnState>,
+    sender: Sender<'_, M, Data, N>,
+) -> Result<Infallible, Error<B::Error>>
+where
+    B: BusOperation,
+    M: RawMutex,
+{
+    let md = sensor.active_mode();
+    let mut ticker = Ticker::every(odr_period(md.odr));
+    loop {
+        ticker.next().await;
+
+        if md.odr == Odr::OneShot {
+            sensor.trigger_sw().await?;
+            Timer::after_micros(md.one_shot_duration().as_micros() as u64).await;
+            while !sensor.status_get().await?.drdy_pres {
+                Timer::after_millis(1).await;
+            }
+        }
+
+        let data = sensor.data_get().await?;
+        sender.send(data).await;
+    }
+}
+
+/// Number of samples drained from the FIFO per bus burst by [`fifo_task`].
+const FIFO_CHUNK: usize = 16;
+
+/// Drains the FIFO on watermark and pushes every sample into a channel.
+///
+/// This function is the producer side of a producer/consumer architecture: an
+/// `embassy_time::Ticker` running at the output data rate kept by the driver polls the FIFO
+/// watermark flag and, once it is set, the stored samples are read with
+/// [`Ilps22qs::fifo_data_get`] and sent to `sender`, so the consumer task never touches the
+/// driver. The sensor must already be configured with [`Ilps22qs::mode_set`] and
+/// [`Ilps22qs::fifo_mode_set`], with a non-zero watermark. When the channel is full, the task
+/// waits for the consumer and the FIFO keeps buffering.
+///
+/// The function runs forever and is meant to be the body of an embassy task.
+///
+/// # Parameters
+///
+/// * `sensor`: The configured sensor.
+/// * `sender`: The channel endpoint receiving the samples, in FIFO order.
+///
+/// # Errors
+///
+/// * `Error::Bus(B)`: Returned, ending the loop, if a bus operation fails.
+pub async fn fifo_task<B, M, const N: usize>(
+    sensor: &mut Ilps22qs<B, OnState>,
+    sender: Sender<'_, M, FifoData, N>,
+) -> Result<Infallible, Error<B::Error>>
+where
+    B: BusOperation,
+    M: RawMutex,
+{
+    let mut ticker = Ticker::every(odr_period(sensor.active_mode().odr));
+    let mut data = [FifoData::default(); FIFO_CHUNK];
+    loop {
+        ticker.next().await;
+
+        if FifoStatus2::read(sensor).await?.fifo_wtm_ia() != PROPERTY_ENABLE {
+            continue;
+        }
+
+        let mut level = sensor.fifo_level_get().await?;
+        while level > 0 {
+            let samp = level.min(FIFO_CHUNK as u8);
+            let samp = sensor.fifo_data_get(samp, &mut data).await?;
+            for value in data.iter().take(samp) {
+                sender.send(*value).await;
+            }
+            level = level.saturating_sub(samp as u8);
+        }
+    }
+}
+
+fn odr_period(odr: Odr) -> Duration {
+    Duration::from_micros(odr.period_us().unwrap_or(1_000_000).into())
+}
@@ -0,0 +1,297 @@
+//! C ABI wrapper of the blocking driver.
+//!
+//! The exported functions let C firmware use this crate as its ILPS22QS driver. The bus is
+//! provided through [`Ilps22qsCtx`], whose fields are laid out like the first fields of ST's
+//! `stmdev_ctx_t`, so the platform callbacks of an existing C project can be reused unchanged.
+//! The functions are stateless, like the C driver: the context is passed to every call and no
+//! driver state is kept between calls.
+//!
+//! Every function returns [`ILPS22QS_RS_OK`] on success or one of the negative `ILPS22QS_RS_ERR_*`
+//! codes. The matching C declarations are in `include/ilps22qs_rs.h`; link the crate into the
+//! firmware through a `staticlib` crate enabling the `ffi` feature.
+//!
+//! ```c
+//! ilps22qs_rs_ctx_t ctx = { platform_write, platform_read, platform_delay, &hi2c1 };
+//! ilps22qs_rs_md_t md = { .odr = 4, .avg = 2 };
+//! ilps22qs_rs_data_t data;
+//!
+//! if (ilps22qs_rs_init(&ctx) == ILPS22QS_RS_OK && ilps22qs_rs_mode_set(&ctx, &md) == ILPS22QS_RS_OK) {
+//!     ilps22qs_rs_data_get(&ctx, &md, &data);
+//! }
+//! ```
+
+use crate::blocking::{BusOperation, Error, ILPS22QS_ID, Ilps22qs, prelude::*};
+use core::ffi::c_void;
+use embedded_hal::delay::DelayNs;
+
+/// The call succeeded.
+pub const ILPS22QS_RS_OK: i32 = 0;
+/// A bus callback failed or is missing.
+pub const ILPS22QS_RS_ERR_BUS: i32 = -1;
+/// A pointer is null or a configuration value is out of range.
+pub const ILPS22QS_RS_ERR_ARG: i32 = -2;
+/// The device did not answer with the ILPS22QS identifier.
+pub const ILPS22QS_RS_ERR_ID: i32 = -3;
+/// The boot procedure did not complete.
+pub const ILPS22QS_RS_ERR_BOOT: i32 = -4;
+/// The software reset did not complete.
+pub const ILPS22QS_RS_ERR_RESET: i32 = -5;
+/// The device did not complete the operation in time.
+pub const ILPS22QS_RS_ERR_TIMEOUT: i32 = -6;
+
+/// Register write callback: `(handle, reg, data, len)`, returning `0` on success.
+pub type WriteReg = unsafe extern "C" fn(*mut c_void, u8, *const u8, u16) -> i32;
+/// Register read callback: `(handle, reg, data, len)`, returning `0` on success.
+pub type ReadReg = unsafe extern "C" fn(*mut c_void, u8, *mut u8, u16) -> i32;
+/// Delay callback, in milliseconds.
+pub type MDelay = unsafe extern "C" fn(u32);
+
+/// Platform bus context, laid out like the first fields of `stmdev_ctx_t`.
+#[repr(C)]
+pub struct Ilps22qsCtx {
+    /// Writes `len` bytes from `data` starting at register `reg`.
+    pub write_reg: Option<WriteReg>,
+    /// Reads `len` bytes into `data` starting at register `reg`.
+    pub read_reg: Option<ReadReg>,
+    /// Waits for the given number of milliseconds; needed by [`ilps22qs_rs_init`].
+    pub mdelay: Option<MDelay>,
+    /// Opaque pointer passed to the bus callbacks.
+    pub handle: *mut c_void,
+}
+
+/// Sensor conversion parameters, with the values of the C driver enumerations.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Ilps22qsMd {
+    /// `1` to enable the interleaved AH/QVAR mode.
+    pub interleaved_mode: u8,
+    /// Full scale: `0` for 1260 hPa, `1` for 4060 hPa.
+    pub fs: u8,
+    /// Output data rate: `0` (one-shot) to `8` (200 Hz).
+    pub odr: u8,
+    /// Averaging: `0` (4 samples) to `7` (512 samples).
+    pub avg: u8,
+    /// Low-pass filter: `0` (disabled), `1` (ODR/4) or `3` (ODR/9).
+    pub lpf: u8,
+}
+
+/// Converted output sample.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Ilps22qsData {
+    /// Pressure, in hPa.
+    pub pressure_hpa: f32,
+    /// Raw pressure output value.
+    pub pressure_raw: i32,
+    /// Temperature, in °C.
+    pub temperature_deg_c: f32,
+    /// AH/QVAR raw value, for samples of an interleaved stream.
+    pub ah_qvar_lsb: i32,
+    /// Raw temperature output value.
+    pub temperature_raw: i16,
+}
+
+impl TryFrom<&Ilps22qsMd> for Md {
+    type Error = ();
+
+    fn try_from(md: &Ilps22qsMd) -> Result<Self, Self::Error> {
+        Ok(Md {
+            interleaved_mode: md.interleaved_mode != 0,
+            fs: Fs::try_from(md.fs).map_err(|_| ())?,
+            odr: Odr::try_from(md.odr).map_err(|_| ())?,
+            avg: Avg::try_from(md.avg).map_err(|_| ())?,
+            lpf: Lpf::try_from(md.lpf).map_err(|_| ())?,
+        })
+    }
+}
+
+impl From<&Md> for Ilps22qsMd {
+    fn from(md: &Md) -> Self {
+        Ilps22qsMd {
+            interleaved_mode: md.interleaved_mode as u8,
+            fs: md.fs as u8,
+            odr: md.odr as u8,
+            avg: md.avg as u8,
+            lpf: md.lpf as u8,
+        }
+    }
+}
+
+impl From<&Data> for Ilps22qsData {
+    fn from(data: &Data) -> Self {
+        Ilps22qsData {
+            pressure_hpa: data.pressure.hpa,
+            pressure_raw: data.pressure.raw,
+            temperature_deg_c: data.heat.deg_c,
+            ah_qvar_lsb: data.ah_qvar.lsb,
+            temperature_raw: data.heat.raw,
+        }
+    }
+}
+
+/// Transport forwarding the register accesses to the context callbacks.
+struct CtxBus<'a>(&'a Ilps22qsCtx);
+
+impl BusOperation for CtxBus<'_> {
+    type Error = i32;
+
+    fn read_bytes(&mut self, _rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        // The callbacks only address registers: the driver never issues bare reads.
+        Err(ILPS22QS_RS_ERR_BUS)
+    }
+
+    fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        let (Some(write_reg), Some((&reg, data))) = (self.0.write_reg, wbuf.split_first()) else {
+            return Err(ILPS22QS_RS_ERR_BUS);
+        };
+        let len = u16::try_from(data.len()).map_err(|_| ILPS22QS_RS_ERR_ARG)?;
+        // SAFETY: `data` is valid for `len` bytes, as required by the callback contract.
+        match unsafe { write_reg(self.0.handle, reg, data.as_ptr(), len) } {
+            0 => Ok(()),
+            err => Err(err),
+        }
+    }
+
+    fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let Some(read_reg) = self.0.read_reg else {
+            return Err(ILPS22QS_RS_ERR_BUS);
+        };
+        let [reg] = *wbuf;
+        let len = u16::try_from(rbuf.len()).map_err(|_| ILPS22QS_RS_ERR_ARG)?;
+        // SAFETY: `rbuf` is valid for writes of `len` bytes, as required by the callback contract.
+        match unsafe { read_reg(self.0.handle, reg, rbuf.as_mut_ptr(), len) } {
+            0 => Ok(()),
+            err => Err(err),
+        }
+    }
+}
+
+/// Delay forwarding to the context callback, rounded up to the millisecond.
+struct CtxDelay<'a>(&'a Ilps22qsCtx);
+
+impl DelayNs for CtxDelay<'_> {
+    fn delay_ns(&mut self, ns: u32) {
+        if let Some(mdelay) = self.0.mdelay {
+            // SAFETY: the callback takes no pointer.
+            unsafe { mdelay(ns.div_ceil(1_000_000)) }
+        }
+    }
+}
+
+fn status(error: Error<i32>) -> i32 {
+    match error {
+        Error::Bus(_) => ILPS22QS_RS_ERR_BUS,
+        Error::Boot => ILPS22QS_RS_ERR_BOOT,
+        Error::SwReset => ILPS22QS_RS_ERR_RESET,
+        Error::Timeout => ILPS22QS_RS_ERR_TIMEOUT,
+        Error::DeviceReset => ILPS22QS_RS_ERR_RESET,
+        Error::Fatal => ILPS22QS_RS_ERR_BUS,
+        Error::BufferTooSmall | Error::Busy => ILPS22QS_RS_ERR_ARG,
+    }
+}
+
+/// Runs `f` on a driver built over `ctx`, converting the result into a status code.
+fn with_sensor(
+    ctx: &Ilps22qsCtx,
+    f: impl FnOnce(&mut Ilps22qs<CtxBus<'_>, OnState>) -> Result<i32, Error<i32>>,
+) -> i32 {
+    let mut sensor = Ilps22qs::from_bus(CtxBus(ctx));
+    f(&mut sensor).unwrap_or_else(status)
+}
+
+/// Checks the device identifier, resets the device and prepares it for normal operation.
+///
+/// # Safety
+///
+/// `ctx` must be null or point to a valid context whose callbacks honour their contract.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ilps22qs_rs_init(ctx: *const Ilps22qsCtx) -> i32 {
+    // SAFETY: guaranteed by the caller.
+    let Some(ctx) = (unsafe { ctx.as_ref() }) else {
+        return ILPS22QS_RS_ERR_ARG;
+    };
+    with_sensor(ctx, |sensor| {
+        if sensor.id_get()?.whoami() != ILPS22QS_ID {
+            return Ok(ILPS22QS_RS_ERR_ID);
+        }
+        let mut delay = CtxDelay(ctx);
+        sensor.init_set(Init::Reset, &mut delay)?;
+        sensor.init_set(Init::DrvRdy, &mut delay)?;
+        Ok(ILPS22QS_RS_OK)
+    })
+}
+
+/// Writes the sensor conversion parameters.
+///
+/// # Safety
+///
+/// `ctx` and `md` must be null or valid, and the callbacks of `ctx` must honour their contract.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ilps22qs_rs_mode_set(
+    ctx: *const Ilps22qsCtx,
+    md: *const Ilps22qsMd,
+) -> i32 {
+    // SAFETY: guaranteed by the caller.
+    let (Some(ctx), Some(md)) = (unsafe { ctx.as_ref() }, unsafe { md.as_ref() }) else {
+        return ILPS22QS_RS_ERR_ARG;
+    };
+    let Ok(md) = Md::try_from(md) else {
+        return ILPS22QS_RS_ERR_ARG;
+    };
+    with_sensor(ctx, |sensor| {
+        sensor.mode_set(&md)?;
+        Ok(ILPS22QS_RS_OK)
+    })
+}
+
+/// Reads the sensor conversion parameters into `md`.
+///
+/// # Safety
+///
+/// `ctx` and `md` must be null or valid, and the callbacks of `ctx` must honour their contract.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ilps22qs_rs_mode_get(ctx: *const Ilps22qsCtx, md: *mut Ilps22qsMd) -> i32 {
+    // SAFETY: guaranteed by the caller.
+    let (Some(ctx), Some(md)) = (unsafe { ctx.as_ref() }, unsafe { md.as_mut() }) else {
+        return ILPS22QS_RS_ERR_ARG;
+    };
+    with_sensor(ctx, |sensor| {
+        *md = Ilps22qsMd::from(&sensor.mode_get()?);
+        Ok(ILPS22QS_RS_OK)
+    })
+}
+
+/// Reads and converts the output registers into `data`.
+///
+/// # Safety
+///
+/// `ctx`, `md` and `data` must be null or valid, and the callbacks of `ctx` must honour their
+/// contract.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ilps22qs_rs_data_get(
+    ctx: *const Ilps22qsCtx,
+    md: *const Ilps22qsMd,
+    data: *mut Ilps22qsData,
+) -> i32 {
+    // SAFETY: guaranteed by the caller.
+    let (Some(ctx), Some(md), Some(data)) =
+        (unsafe { ctx.as_ref() }, unsafe { md.as_ref() }, unsafe {
+            data.as_mut()
+        })
+    else {
+        return ILPS22QS_RS_ERR_ARG;
+    };
+    let Ok(md) = Md::try_from(md) else {
+        return ILPS22QS_RS_ERR_ARG;
+    };
+    with_sensor(ctx, |sensor| {
+        // The driver is rebuilt at every call: convert with the parameters of the caller
+        sensor.md = md;
+        *data = Ilps22qsData::from(&sensor.data_get()?);
+        Ok(ILPS22QS_RS_OK)
+    })
+}
@@ -0,0 +1,377 @@
+//! Software filters for converted sensor samples.
+//!
+//! The filters operate on already converted values (e.g. pressure in hPa) and are independent of
+//! the bus flavour, so the same types are shared by the asynchronous and blocking drivers.
+//! Every filter implements [`SampleFilter`] and can be chained with [`SampleFilterExt::then`].
+
+/// A filter processing one sample at a time.
+pub trait SampleFilter {
+    /// Feeds `sample` into the filter and returns the filtered value.
+    fn update(&mut self, sample: f32) -> f32;
+
+    /// Clears the filter history.
+    fn reset(&mut self);
+}
+
+/// Represents the strategy used by [`SpikeFilter`] to reject outliers.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SpikeMode {
+    /// Outputs the median of the last three samples.
+    ///
+    /// A single-sample glitch never reaches the output, at the cost of one sample of latency on
+    /// genuine steps.
+    Median3,
+    /// Limits the change between consecutive outputs to `max_step`.
+    ///
+    /// Glitches are clipped to `max_step`, while genuine steps are followed at that rate.
+    RateLimit {
+        /// Largest accepted change between two consecutive outputs, in sample units.
+        max_step: f32,
+    },
+}
+
+/// Spike-rejection filter.
+///
+/// The `SpikeFilter` suppresses single-sample glitches caused by vibration or bus hiccups. It can
+/// be installed in the driver data path or used standalone through [`SampleFilter`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SpikeFilter {
+    mode: SpikeMode,
+    history: [f32; 3],
+    len: u8,
+}
+
+impl SpikeFilter {
+    /// Creates a median-of-3 spike filter.
+    pub const fn median3() -> Self {
+        Self::new(SpikeMode::Median3)
+    }
+
+    /// Creates a rate-limiting spike filter accepting at most `max_step` per sample.
+    pub const fn rate_limit(max_step: f32) -> Self {
+        Self::new(SpikeMode::RateLimit { max_step })
+    }
+
+    /// Creates a spike filter using the given strategy.
+    pub const fn new(mode: SpikeMode) -> Self {
+        Self {
+            mode,
+            history: [0.0; 3],
+            len: 0,
+        }
+    }
+
+    /// Returns the rejection strategy.
+    pub const fn mode(&self) -> SpikeMode {
+        self.mode
+    }
+}
+
+impl SampleFilter for SpikeFilter {
+    fn update(&mut self, sample: f32) -> f32 {
+        let [a, b, _] = self.history;
+        match self.mode {
+            SpikeMode::Median3 => {
+                self.history = [sample, a, b];
+                self.len = (self.len + 1).min(3);
+                if self.len < 3 {
+                    return sample;
+                }
+                let [x, y, z] = self.history;
+                x.max(y).min(y.max(z)).min(x.max(z))
+            }
+            SpikeMode::RateLimit { max_step } => {
+                let out = if self.len == 0 {
+                    sample
+                } else {
+                    let step = sample - a;
+                    if step > max_step {
+                        a + max_step
+                    } else if step < -max_step {
+                        a - max_step
+                    } else {
+                        sample
+                    }
+                };
+                self.history = [out, a, b];
+                self.len = 1;
+                out
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.history = [0.0; 3];
+        self.len = 0;
+    }
+}
+
+/// Median filter over a sliding window of `N` samples.
+///
+/// The `Median` filter rejects outliers while preserving edges better than averaging filters,
+/// which makes it well suited to noisy pneumatic setups. Until the window is filled, the median
+/// of the samples received so far is returned. For an even number of samples the mean of the
+/// two central values is used.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Median<const N: usize> {
+    window: [f32; N],
+    next: usize,
+    len: usize,
+}
+
+impl<const N: usize> Median<N> {
+    /// Creates an empty median filter.
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "median window must not be empty") };
+        Self {
+            window: [0.0; N],
+            next: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<const N: usize> Default for Median<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> SampleFilter for Median<N> {
+    fn update(&mut self, sample: f32) -> f32 {
+        if let Some(slot) = self.window.get_mut(self.next) {
+            *slot = sample;
+        }
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+
+        let mut sorted = self.window;
+        let Some(sorted) = sorted.get_mut(..self.len) else {
+            return sample;
+        };
+        sorted.sort_unstable_by(f32::total_cmp);
+
+        let mid = self.len / 2;
+        match (
+            sorted.get(mid),
+            mid.checked_sub(1).and_then(|i| sorted.get(i)),
+        ) {
+            (Some(hi), Some(lo)) if self.len.is_multiple_of(2) => (hi + lo) / 2.0,
+            (Some(hi), _) => *hi,
+            _ => sample,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+}
+
+/// Moving-average filter over a sliding window of `N` samples.
+///
+/// Until the window is filled, the mean of the samples received so far is returned.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MovingAverage<const N: usize> {
+    window: [f32; N],
+    next: usize,
+    len: usize,
+    sum: f32,
+}
+
+impl<const N: usize> MovingAverage<N> {
+    /// Creates an empty moving-average filter.
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "moving-average window must not be empty") };
+        Self {
+            window: [0.0; N],
+            next: 0,
+            len: 0,
+            sum: 0.0,
+        }
+    }
+}
+
+impl<const N: usize> Default for MovingAverage<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> SampleFilter for MovingAverage<N> {
+    fn update(&mut self, sample: f32) -> f32 {
+        if let Some(slot) = self.window.get_mut(self.next) {
+            if self.len == N {
+                self.sum -= *slot;
+            }
+            *slot = sample;
+        }
+        self.sum += sample;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+
+        self.sum / self.len as f32
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+        self.sum = 0.0;
+    }
+}
+
+/// Exponentially weighted moving-average filter.
+///
+/// The `Ewma` filter outputs `y += alpha * (x - y)`, keeping a single value of state, which makes
+/// it the cheapest smoothing option when a window does not fit in RAM. A small `alpha` smooths
+/// more at the cost of a slower response; `1.0` passes the samples through. The first sample
+/// initializes the output.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ewma {
+    alpha: f32,
+    value: Option<f32>,
+}
+
+impl Ewma {
+    /// Creates an EWMA filter with the given smoothing factor, clamped to the `0.0..=1.0` range.
+    pub const fn new(alpha: f32) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            value: None,
+        }
+    }
+
+    /// Returns the smoothing factor.
+    pub const fn alpha(&self) -> f32 {
+        self.alpha
+    }
+}
+
+impl SampleFilter for Ewma {
+    fn update(&mut self, sample: f32) -> f32 {
+        let value = match self.value {
+            Some(value) => value + self.alpha * (sample - value),
+            None => sample,
+        };
+        self.value = Some(value);
+        value
+    }
+
+    fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+/// Two filters applied in sequence, created with [`SampleFilterExt::then`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: SampleFilter, B: SampleFilter> SampleFilter for Chain<A, B> {
+    fn update(&mut self, sample: f32) -> f32 {
+        let sample = self.first.update(sample);
+        self.second.update(sample)
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+/// Composition helpers available on every [`SampleFilter`].
+pub trait SampleFilterExt: SampleFilter + Sized {
+    /// Returns a filter feeding the output of `self` into `next`.
+    ///
+    /// ```ignore
+    /// let mut filter = Median::<5>::new().then(MovingAverage::<8>::new());
+    /// let hpa = filter.update(data.pressure.hpa);
+    /// ```
+    fn then<F: SampleFilter>(self, next: F) -> Chain<Self, F> {
+        Chain {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+impl<F: SampleFilter> SampleFilterExt for F {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(filter: &mut impl SampleFilter, samples: &[f32]) -> [f32; 8] {
+        let mut out = [f32::NAN; 8];
+        for (out, &sample) in out.iter_mut().zip(samples) {
+            *out = filter.update(sample);
+        }
+        out
+    }
+
+    #[test]
+    fn median3_passes_warm_up_and_rejects_a_spike() {
+        let mut filter = SpikeFilter::median3();
+        let out = feed(&mut filter, &[1.0, 2.0, 3.0, 3.0, 100.0, 3.0, 3.0]);
+        assert_eq!(out[..7], [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0]);
+
+        filter.reset();
+        assert_eq!(filter.update(50.0), 50.0);
+    }
+
+    #[test]
+    fn rate_limit_passes_the_first_sample_and_clips_steps() {
+        let mut filter = SpikeFilter::rate_limit(1.0);
+        let out = feed(&mut filter, &[100.0, 105.0, 101.5, 90.0, 100.5]);
+        assert_eq!(out[..5], [100.0, 101.0, 101.5, 100.5, 100.5]);
+
+        filter.reset();
+        assert_eq!(filter.update(50.0), 50.0);
+    }
+
+    #[test]
+    fn median_warms_up_with_even_counts_and_slides() {
+        let mut filter = Median::<4>::new();
+        let out = feed(&mut filter, &[1.0, 3.0, 2.0, 10.0, 4.0, 4.0]);
+        assert_eq!(out[..6], [1.0, 2.0, 2.0, 2.5, 3.5, 4.0]);
+
+        let mut filter = Median::<5>::new();
+        let out = feed(&mut filter, &[10.0, 10.0, 10.0, 10.0, 100.0, 10.0]);
+        assert_eq!(out[..6], [10.0; 6]);
+    }
+
+    #[test]
+    fn moving_average_warms_up_and_wraps() {
+        let mut filter = MovingAverage::<3>::new();
+        let out = feed(&mut filter, &[3.0, 6.0, 9.0, 12.0, 0.0, 0.0, 0.0]);
+        assert_eq!(out[..7], [3.0, 4.5, 6.0, 9.0, 7.0, 4.0, 0.0]);
+
+        filter.reset();
+        assert_eq!(filter.update(5.0), 5.0);
+    }
+
+    #[test]
+    fn ewma_starts_at_the_first_sample() {
+        assert_eq!(Ewma::new(2.0).alpha(), 1.0);
+        assert_eq!(Ewma::new(-1.0).alpha(), 0.0);
+
+        let mut filter = Ewma::new(0.5);
+        let out = feed(&mut filter, &[10.0, 20.0, 20.0, 100.0, 20.0]);
+        assert_eq!(out[..5], [10.0, 15.0, 17.5, 58.75, 39.375]);
+
+        filter.reset();
+        assert_eq!(filter.update(5.0), 5.0);
+    }
+
+    #[test]
+    fn chain_feeds_the_first_filter_into_the_second() {
+        let mut filter = Median::<3>::new().then(MovingAverage::<2>::new());
+        let out = feed(&mut filter, &[10.0, 10.0, 10.0, 100.0, 10.0, 20.0, 20.0]);
+        assert_eq!(out[..7], [10.0, 10.0, 10.0, 10.0, 10.0, 15.0, 20.0]);
+
+        filter.reset();
+        assert_eq!(filter.update(4.0), 4.0);
+    }
+}
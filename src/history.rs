@@ -0,0 +1,134 @@
+//! Long-duration history of converted sensor samples.
+//!
+//! [`History`] aggregates every `k` consecutive samples into a [`Bucket`] keeping the last
+//! sample together with the minimum and maximum seen in between, so short excursions are not
+//! lost by the decimation. With one sample per second and `k = 60`, `History::<240>` keeps 4 h
+//! of per-minute data in less than 3 KiB.
+
+/// Represents `k` consecutive samples aggregated by a [`History`].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Bucket {
+    /// The last sample of the bucket.
+    pub sample: f32,
+    /// The smallest sample of the bucket.
+    pub min: f32,
+    /// The largest sample of the bucket.
+    pub max: f32,
+}
+
+/// Decimating history holding the last `N` buckets.
+///
+/// ```ignore
+/// let mut history = History::<240>::new(60);
+/// history.push(data.pressure.hpa);
+/// let (lo, hi) = history.iter().fold((f32::MAX, f32::MIN), |(lo, hi), b| (lo.min(b.min), hi.max(b.max)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct History<const N: usize> {
+    buckets: [Bucket; N],
+    next: usize,
+    len: usize,
+    decimation: u16,
+    pending: Option<(Bucket, u16)>,
+}
+
+impl<const N: usize> History<N> {
+    /// Creates an empty history aggregating `decimation` samples per bucket.
+    ///
+    /// A decimation of `0` or `1` stores every sample.
+    pub const fn new(decimation: u16) -> Self {
+        const { assert!(N > 0, "history must hold at least one bucket") };
+        Self {
+            buckets: [Bucket {
+                sample: 0.0,
+                min: 0.0,
+                max: 0.0,
+            }; N],
+            next: 0,
+            len: 0,
+            decimation: if decimation == 0 { 1 } else { decimation },
+            pending: None,
+        }
+    }
+
+    /// Feeds a sample to the history.
+    ///
+    /// Returns `true` if the sample completed a bucket, discarding the oldest bucket if the
+    /// history is full.
+    pub fn push(&mut self, sample: f32) -> bool {
+        let (bucket, count) = match self.pending {
+            Some((bucket, count)) => (
+                Bucket {
+                    sample,
+                    min: bucket.min.min(sample),
+                    max: bucket.max.max(sample),
+                },
+                count + 1,
+            ),
+            None => (
+                Bucket {
+                    sample,
+                    min: sample,
+                    max: sample,
+                },
+                1,
+            ),
+        };
+
+        if count < self.decimation {
+            self.pending = Some((bucket, count));
+            return false;
+        }
+
+        if let Some(slot) = self.buckets.get_mut(self.next) {
+            *slot = bucket;
+        }
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+        self.pending = None;
+        true
+    }
+
+    /// Clears the history, including the partially filled bucket.
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+        self.pending = None;
+    }
+
+    /// Returns the number of samples aggregated per bucket.
+    pub const fn decimation(&self) -> u16 {
+        self.decimation
+    }
+
+    /// Returns the number of completed buckets.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bucket was completed since the creation or the last reset.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` once the history holds `N` buckets.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the partially filled bucket, if any sample was pushed since the last completed one.
+    pub fn pending(&self) -> Option<Bucket> {
+        self.pending.map(|(bucket, _)| bucket)
+    }
+
+    /// Returns the completed buckets, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = Bucket> + '_ {
+        let oldest = (self.next + N - self.len) % N;
+        self.buckets
+            .iter()
+            .cycle()
+            .skip(oldest)
+            .take(self.len)
+            .copied()
+    }
+}
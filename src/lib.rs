@@ -1,21 +1,84 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 #![allow(clippy::duplicate_mod)]
+// No public API may panic: slices are accessed with `get`, and fallible conversions are
+// propagated as errors or handled with an explicit fallback.
+#![deny(
+    clippy::indexing_slicing,
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::unreachable,
+    clippy::todo,
+    clippy::unimplemented
+)]
+
+#[cfg(feature = "float")]
+pub mod altitude;
+#[cfg(any(feature = "libm", feature = "micromath"))]
+pub mod barograph;
+pub mod clock;
+#[cfg(feature = "float")]
+pub mod depth;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "float")]
+pub mod filter;
+#[cfg(feature = "float")]
+pub mod history;
+#[cfg(feature = "float")]
+pub mod logger;
+#[cfg(any(feature = "libm", feature = "micromath"))]
+mod math;
+#[cfg(feature = "sim")]
+pub mod profile;
+#[cfg(feature = "qvar")]
+pub mod qvar;
+#[cfg(feature = "float")]
+pub mod respiration;
+#[cfg(feature = "float")]
+pub mod stats;
+#[cfg(feature = "storage")]
+pub mod storage;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+#[cfg(feature = "float")]
+pub mod trend;
 
 #[cfg(feature = "async")]
 #[path = "."]
 pub mod asynchronous {
     use bisync::asynchronous::*;
+    use embedded_hal::digital::OutputPin;
     use embedded_hal_async::delay::DelayNs;
     use embedded_hal_async::i2c::{I2c, SevenBitAddress};
+    use embedded_hal_async::spi::SpiBus as RawSpiBus;
     use embedded_hal_async::spi::SpiDevice;
+    #[cfg(feature = "bridge")]
+    use embedded_io_async::{Read, Write};
     use st_mems_bus::asynchronous::*;
 
+    pub mod bus;
+    pub mod compat;
+    #[cfg(feature = "csv")]
+    pub mod csv;
+    pub mod decode;
     pub mod driver;
+    #[cfg(feature = "embassy")]
+    pub mod embassy;
     pub mod prelude;
     pub mod register;
+    #[cfg(feature = "rtic")]
+    pub mod rtic;
+    #[cfg(feature = "float")]
+    pub mod sensor;
+    pub mod split;
 
     pub use driver::*;
+    #[cfg(feature = "float")]
+    pub use sensor::{PressureSensor, TemperatureSensor};
+    pub use split::{Config, Reader};
+    pub use st_mems_bus::asynchronous::BusOperation;
 }
 
 #[cfg(feature = "blocking")]
@@ -23,13 +86,39 @@ pub mod asynchronous {
 pub mod blocking {
     use bisync::synchronous::*;
     use embedded_hal::delay::DelayNs;
+    use embedded_hal::digital::OutputPin;
     use embedded_hal::i2c::{I2c, SevenBitAddress};
+    use embedded_hal::spi::SpiBus as RawSpiBus;
     use embedded_hal::spi::SpiDevice;
+    #[cfg(feature = "bridge")]
+    use embedded_io::{Read, Write};
     use st_mems_bus::blocking::*;
 
+    pub mod bus;
+    pub mod compat;
+    #[cfg(feature = "csv")]
+    pub mod csv;
+    pub mod decode;
     pub mod driver;
+    #[cfg(feature = "nb")]
+    pub mod polling;
+    #[cfg(feature = "power_down_on_drop")]
+    pub mod power_down;
     pub mod prelude;
     pub mod register;
+    #[cfg(feature = "rtic")]
+    pub mod rtic;
+    #[cfg(feature = "float")]
+    pub mod sensor;
+    #[cfg(feature = "shared")]
+    pub mod shared;
+    pub mod split;
 
     pub use driver::*;
+    #[cfg(feature = "float")]
+    pub use sensor::{PressureSensor, TemperatureSensor};
+    #[cfg(feature = "shared")]
+    pub use shared::SharedIlps22qs;
+    pub use split::{Config, Reader};
+    pub use st_mems_bus::blocking::BusOperation;
 }
@@ -0,0 +1,145 @@
+//! Ring-buffer logger of converted sensor samples.
+//!
+//! [`Logger`] continuously records decimated samples, overwriting the oldest ones, and can be
+//! frozen when an event occurs so the history preceding it (and optionally a few samples after
+//! it) can be dumped for analysis, either by iterating [`Logger::dump`] or into a
+//! [`LogBackend`] with [`Logger::dump_to`].
+
+/// Destination persisting the samples dumped by a [`Logger`].
+pub trait LogBackend {
+    /// Error reported by the backend.
+    type Error;
+
+    /// Stores `samples`, oldest first, after the previously stored ones.
+    fn store(&mut self, samples: impl Iterator<Item = f32>) -> Result<(), Self::Error>;
+}
+
+/// Ring-buffer logger holding the last `N` recorded samples.
+///
+/// ```ignore
+/// let mut logger = Logger::<256>::new(10);
+/// loop {
+///     let hpa = sensor.data_get()?.pressure.hpa;
+///     logger.push(hpa);
+///     if burst_detected(hpa) {
+///         logger.trigger(32);
+///     }
+///     if logger.is_frozen() {
+///         logger.dump().for_each(|hpa| send(hpa));
+///         logger.resume();
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Logger<const N: usize> {
+    window: [f32; N],
+    next: usize,
+    len: usize,
+    decimation: u16,
+    skipped: u16,
+    post_trigger: Option<usize>,
+}
+
+impl<const N: usize> Logger<N> {
+    /// Creates an empty logger recording one sample out of `decimation`.
+    ///
+    /// A decimation of `0` or `1` records every sample.
+    pub const fn new(decimation: u16) -> Self {
+        const { assert!(N > 0, "logger window must not be empty") };
+        Self {
+            window: [0.0; N],
+            next: 0,
+            len: 0,
+            decimation: if decimation == 0 { 1 } else { decimation },
+            skipped: 0,
+            post_trigger: None,
+        }
+    }
+
+    /// Feeds a sample to the logger.
+    ///
+    /// The sample is recorded if it is not dropped by the decimation and the logger is not frozen.
+    pub fn push(&mut self, sample: f32) {
+        if self.is_frozen() {
+            return;
+        }
+
+        self.skipped += 1;
+        if self.skipped < self.decimation {
+            return;
+        }
+        self.skipped = 0;
+
+        if let Some(slot) = self.window.get_mut(self.next) {
+            *slot = sample;
+        }
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+
+        if let Some(remaining) = &mut self.post_trigger {
+            *remaining = remaining.saturating_sub(1);
+        }
+    }
+
+    /// Signals an event: the logger freezes after recording `post_samples` more samples.
+    ///
+    /// Subsequent triggers are ignored until [`Logger::resume`] is called.
+    pub fn trigger(&mut self, post_samples: usize) {
+        if self.post_trigger.is_none() {
+            self.post_trigger = Some(post_samples.min(N));
+        }
+    }
+
+    /// Freezes the logger immediately, equivalent to `trigger(0)`.
+    pub fn freeze(&mut self) {
+        self.trigger(0);
+    }
+
+    /// Returns `true` once the logger stopped recording after an event.
+    pub fn is_frozen(&self) -> bool {
+        self.post_trigger == Some(0)
+    }
+
+    /// Resumes recording, keeping the current history.
+    pub fn resume(&mut self) {
+        self.post_trigger = None;
+    }
+
+    /// Clears the history and resumes recording.
+    pub fn clear(&mut self) {
+        self.next = 0;
+        self.len = 0;
+        self.skipped = 0;
+        self.post_trigger = None;
+    }
+
+    /// Returns the number of recorded samples.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no sample is recorded.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the recorded samples, oldest first.
+    pub fn dump(&self) -> impl Iterator<Item = f32> + '_ {
+        let oldest = (self.next + N - self.len) % N;
+        self.window
+            .iter()
+            .cycle()
+            .skip(oldest)
+            .take(self.len)
+            .copied()
+    }
+
+    /// Stores the recorded samples, oldest first, into `backend`.
+    ///
+    /// # Errors
+    ///
+    /// * `L::Error`: Returned if the backend fails.
+    pub fn dump_to<L: LogBackend>(&self, backend: &mut L) -> Result<(), L::Error> {
+        backend.store(self.dump())
+    }
+}
@@ -0,0 +1,30 @@
+//! Math backend for the `no_std` formulas.
+//!
+//! `core` does not provide the transcendental `f32` functions, so they are routed to the
+//! backend selected by the crate features: `libm` when enabled, `micromath` otherwise. The
+//! `micromath` approximations are less accurate but take far fewer cycles on cores without an
+//! FPU or with a single precision FPU only.
+
+/// Raises `x` to the floating point power `y`.
+#[cfg(feature = "libm")]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    libm::powf(x, y)
+}
+
+/// Raises `x` to the floating point power `y`.
+#[cfg(all(feature = "micromath", not(feature = "libm")))]
+pub(crate) fn powf(x: f32, y: f32) -> f32 {
+    micromath::F32Ext::powf(x, y)
+}
+
+/// Computes the sine of `x`, in radians.
+#[cfg(all(feature = "sim", feature = "libm"))]
+pub(crate) fn sinf(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+/// Computes the sine of `x`, in radians.
+#[cfg(all(feature = "sim", feature = "micromath", not(feature = "libm")))]
+pub(crate) fn sinf(x: f32) -> f32 {
+    micromath::F32Ext::sin(x)
+}
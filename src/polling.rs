@@ -0,0 +1,57 @@
+//! `nb`-flavoured methods for polling state machines.
+//!
+//! Instead of waiting for the sensor, these methods return `nb::Error::WouldBlock` when no data
+//! is available yet, so they can be driven by `nb::block!` or by a custom scheduler.
+
+use super::{BusOperation, Error, Ilps22qs, PROPERTY_DISABLE, RegisterOperation, prelude::*};
+
+impl<B: BusOperation> Ilps22qs<B, OnState> {
+    /// Reads the output data if a new pressure sample is available.
+    ///
+    /// # Returns
+    ///
+    /// * `nb::Result<Data, Error<B::Error>>`
+    ///     * `Ok(Data)`: The converted sample, as returned by [`Ilps22qs::data_get`].
+    ///     * `Err(nb::Error::WouldBlock)`: No new pressure sample is available yet.
+    ///
+    /// # Errors
+    ///
+    /// * `nb::Error::Other(Error::Bus(B))`: Occurs if there is a communication issue with the
+    ///   device.
+    pub fn read_data_nb(&mut self) -> nb::Result<Data, Error<B::Error>> {
+        if Status::read(self)?.p_da() == PROPERTY_DISABLE {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        Ok(self.data_get()?)
+    }
+
+    /// Reads the samples currently stored in the FIFO, if any.
+    ///
+    /// At most `data.len()` samples are read; remaining samples are left in the FIFO for the
+    /// next call.
+    ///
+    /// # Parameters
+    ///
+    /// * `data`: A mutable slice of `FifoData` receiving the samples.
+    ///
+    /// # Returns
+    ///
+    /// * `nb::Result<usize, Error<B::Error>>`
+    ///     * `Ok(usize)`: The number of samples stored at the beginning of `data`.
+    ///     * `Err(nb::Error::WouldBlock)`: The FIFO is empty.
+    ///
+    /// # Errors
+    ///
+    /// * `nb::Error::Other(Error::Bus(B))`: Occurs if there is a communication issue with the
+    ///   device.
+    pub fn read_fifo_nb(&mut self, data: &mut [FifoData]) -> nb::Result<usize, Error<B::Error>> {
+        let level = self.fifo_level_get()?;
+        if level == 0 {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        let samp = data.len().min(level as usize);
+        Ok(self.fifo_data_get(samp as u8, data)?)
+    }
+}
@@ -0,0 +1,78 @@
+//! Power-down of the device when the driver is dropped.
+//!
+//! Dropping a driver left in continuous mode would keep the device converting, and drawing
+//! current, until the next reset. With the `power_down_on_drop` feature, a blocking driver
+//! wrapped in [`PowerDownOnDrop`] places the device in power-down (one-shot ODR) and disables
+//! AH/QVAR when the wrapper is dropped. Bus errors are ignored, since `Drop` cannot report them.
+//! The asynchronous driver cannot issue bus transactions from `Drop`: call `stop_sampling` before
+//! dropping it.
+
+use super::{BusOperation, Ilps22qs, PROPERTY_DISABLE, SensorOperation, prelude::*};
+
+use core::ops::{Deref, DerefMut};
+
+/// ODR bits of CTRL_REG1.
+const CTRL_REG1_ODR: u8 = 0x78;
+/// AH_QVAR_EN bit of CTRL_REG3.
+const CTRL_REG3_AH_QVAR_EN: u8 = 0x80;
+
+/// Blocking driver powering the device down when dropped.
+///
+/// The wrapper dereferences to the driver, so it is used like the driver itself. Drivers not
+/// wrapped are never powered down on drop, e.g. to hand the running device over to other code
+/// with [`Ilps22qs::release`].
+///
+/// # Example
+///
+/// ```ignore
+/// let mut sensor = PowerDownOnDrop::new(Ilps22qs::new_i2c(i2c, I2CAddress::SD0High));
+///
+/// sensor.start_sampling(&md)?;
+/// ```
+pub struct PowerDownOnDrop<B: BusOperation, S: SensorState> {
+    sensor: Ilps22qs<B, S>,
+}
+
+impl<B: BusOperation, S: SensorState> PowerDownOnDrop<B, S> {
+    /// Wraps a driver instance.
+    pub const fn new(sensor: Ilps22qs<B, S>) -> Self {
+        Self { sensor }
+    }
+}
+
+impl<B: BusOperation, S: SensorState> Deref for PowerDownOnDrop<B, S> {
+    type Target = Ilps22qs<B, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sensor
+    }
+}
+
+impl<B: BusOperation, S: SensorState> DerefMut for PowerDownOnDrop<B, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.sensor
+    }
+}
+
+impl<B: BusOperation, S: SensorState> Drop for PowerDownOnDrop<B, S> {
+    fn drop(&mut self) {
+        let sensor = &mut self.sensor;
+        let _ = sensor.clear_bits(Reg::CtrlReg1, CTRL_REG1_ODR);
+        if sensor.clear_bits(Reg::CtrlReg3, CTRL_REG3_AH_QVAR_EN) == Some(true) {
+            let _ = sensor.write_to_register(Reg::AnalogicHubDisable as u8, &[PROPERTY_DISABLE]);
+        }
+    }
+}
+
+impl<B: BusOperation, S: SensorState> Ilps22qs<B, S> {
+    /// Clears `mask` in `reg`, returning whether any of its bits was set, or `None` on bus errors.
+    fn clear_bits(&mut self, reg: Reg, mask: u8) -> Option<bool> {
+        let mut value = [0];
+        self.read_from_register(reg as u8, &mut value).ok()?;
+        let [value] = value;
+        if value & mask != 0 {
+            self.write_to_register(reg as u8, &[value & !mask]).ok()?;
+        }
+        Some(value & mask != 0)
+    }
+}
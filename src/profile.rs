@@ -0,0 +1,164 @@
+//! Synthetic pressure profiles.
+//!
+//! A [`Profile`] describes the pressure seen by the sensor over time. Profiles drive the
+//! simulated transport of the `bus::sim` module, so application logic (floor detection, storm
+//! alerts, ...) can be developed and regression-tested before the hardware is available. Any
+//! `FnMut(f32) -> f32` closure is a profile, which allows composing the building blocks below.
+//!
+//! ```ignore
+//! // Ground floor, one floor up (~0.4 hPa) after 10 s, with ±0.05 hPa of noise
+//! let stairs = Step::new(1013.25, 1012.85, 10.0);
+//! let profile = Noisy::new(stairs, 0.05, 1);
+//! ```
+
+/// A pressure profile over time.
+pub trait Profile {
+    /// Returns the pressure in hPa at `time_s` seconds from the start of the playback.
+    fn pressure_hpa(&mut self, time_s: f32) -> f32;
+}
+
+impl<F: FnMut(f32) -> f32> Profile for F {
+    fn pressure_hpa(&mut self, time_s: f32) -> f32 {
+        self(time_s)
+    }
+}
+
+/// Constant pressure.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Constant(pub f32);
+
+impl Profile for Constant {
+    fn pressure_hpa(&mut self, _time_s: f32) -> f32 {
+        self.0
+    }
+}
+
+/// Pressure changing at a constant rate.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ramp {
+    /// The pressure at the start of the playback, in hPa.
+    pub start_hpa: f32,
+    /// The rate of change, in hPa per second.
+    pub rate_hpa_per_s: f32,
+}
+
+impl Ramp {
+    /// Creates a ramp starting at `start_hpa` and changing by `rate_hpa_per_s`.
+    pub const fn new(start_hpa: f32, rate_hpa_per_s: f32) -> Self {
+        Self {
+            start_hpa,
+            rate_hpa_per_s,
+        }
+    }
+}
+
+impl Profile for Ramp {
+    fn pressure_hpa(&mut self, time_s: f32) -> f32 {
+        self.start_hpa + self.rate_hpa_per_s * time_s
+    }
+}
+
+/// Pressure stepping from one value to another at a given time.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Step {
+    /// The pressure before the step, in hPa.
+    pub before_hpa: f32,
+    /// The pressure from the step on, in hPa.
+    pub after_hpa: f32,
+    /// The time of the step, in seconds.
+    pub at_s: f32,
+}
+
+impl Step {
+    /// Creates a step from `before_hpa` to `after_hpa` at `at_s`.
+    pub const fn new(before_hpa: f32, after_hpa: f32, at_s: f32) -> Self {
+        Self {
+            before_hpa,
+            after_hpa,
+            at_s,
+        }
+    }
+}
+
+impl Profile for Step {
+    fn pressure_hpa(&mut self, time_s: f32) -> f32 {
+        if time_s < self.at_s {
+            self.before_hpa
+        } else {
+            self.after_hpa
+        }
+    }
+}
+
+/// Pressure oscillating around a mean value.
+///
+/// Available with the `libm` or `micromath` feature.
+#[cfg(any(feature = "libm", feature = "micromath"))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sine {
+    /// The mean pressure, in hPa.
+    pub mean_hpa: f32,
+    /// The peak deviation from the mean, in hPa.
+    pub amplitude_hpa: f32,
+    /// The oscillation period, in seconds.
+    pub period_s: f32,
+}
+
+#[cfg(any(feature = "libm", feature = "micromath"))]
+impl Sine {
+    /// Creates a sinusoid around `mean_hpa`.
+    pub const fn new(mean_hpa: f32, amplitude_hpa: f32, period_s: f32) -> Self {
+        Self {
+            mean_hpa,
+            amplitude_hpa,
+            period_s,
+        }
+    }
+}
+
+#[cfg(any(feature = "libm", feature = "micromath"))]
+impl Profile for Sine {
+    fn pressure_hpa(&mut self, time_s: f32) -> f32 {
+        let phase = core::f32::consts::TAU * time_s / self.period_s;
+        self.mean_hpa + self.amplitude_hpa * crate::math::sinf(phase)
+    }
+}
+
+/// Profile with added uniform noise.
+///
+/// The noise is generated by a xorshift generator, so a given seed always plays back the same
+/// sequence.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Noisy<P> {
+    /// The noiseless profile.
+    pub profile: P,
+    /// The largest deviation added to the profile, in hPa.
+    pub amplitude_hpa: f32,
+    state: u32,
+}
+
+impl<P> Noisy<P> {
+    /// Adds noise within `±amplitude_hpa` to `profile`, seeding the generator with `seed`.
+    pub const fn new(profile: P, amplitude_hpa: f32, seed: u32) -> Self {
+        Self {
+            profile,
+            amplitude_hpa,
+            // xorshift gets stuck on 0
+            state: if seed == 0 { 0x9E37_79B9 } else { seed },
+        }
+    }
+
+    fn next_unit(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        // 24 random bits mapped to [-1, 1]
+        (self.state >> 8) as f32 / (1 << 23) as f32 - 1.0
+    }
+}
+
+impl<P: Profile> Profile for Noisy<P> {
+    fn pressure_hpa(&mut self, time_s: f32) -> f32 {
+        self.profile.pressure_hpa(time_s) + self.amplitude_hpa * self.next_unit()
+    }
+}
@@ -0,0 +1,526 @@
+//! Signal processing for the AH/QVAR electrostatic channel.
+//!
+//! The algorithms operate on converted AH/QVAR samples in mV (e.g. `AhQvarData::mv`) and are
+//! independent of the bus flavour. [`Baseline`] removes the slow drift of the signal,
+//! [`PresenceDetector`] reports the approach of a human body from the charge variation it
+//! induces on the electrode and [`LevelDetector`] the presence or level of a liquid from the
+//! electrode capacitance change. [`FrequencyEstimator`] measures the dominant frequency of the
+//! signal, e.g. to detect mains hum.
+
+/// Baseline (DC drift) estimator.
+///
+/// The `Baseline` tracks the slow component of the signal with a first-order low-pass filter
+/// and returns the signal with that component removed. Freezing it while an event is in
+/// progress keeps the event from being absorbed into the baseline.
+///
+/// ```ignore
+/// let mut baseline = Baseline::with_time_constant(30.0, 50.0);
+/// let ac_mv = baseline.update(sensor.ah_qvar_data_get().await?.mv);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Baseline {
+    alpha: f32,
+    value: Option<f32>,
+    frozen: bool,
+}
+
+impl Baseline {
+    /// Creates an estimator giving each new sample the weight `alpha`, between 0 and 1.
+    ///
+    /// The time constant is about `1 / alpha` samples.
+    pub const fn new(alpha: f32) -> Self {
+        Self {
+            alpha,
+            value: None,
+            frozen: false,
+        }
+    }
+
+    /// Creates an estimator with a time constant of `tau_s` seconds at `rate_hz` samples per second.
+    pub fn with_time_constant(tau_s: f32, rate_hz: f32) -> Self {
+        let dt_s = 1.0 / rate_hz;
+        Self::new(dt_s / (tau_s + dt_s))
+    }
+
+    /// Returns the weight of each new sample.
+    pub const fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Sets the weight of each new sample, keeping the current baseline.
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha;
+    }
+
+    /// Returns the current baseline, in mV, or `None` before the first sample.
+    pub const fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    /// Stops tracking: the baseline keeps its current value until [`Baseline::resume`].
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Resumes tracking.
+    pub fn resume(&mut self) {
+        self.frozen = false;
+    }
+
+    /// Returns `true` while the baseline is frozen.
+    pub const fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Clears the baseline; the next sample initializes it.
+    pub fn reset(&mut self) {
+        self.value = None;
+    }
+
+    /// Feeds a sample in mV and returns it with the baseline removed.
+    ///
+    /// The first sample initializes the baseline, even while frozen.
+    pub fn update(&mut self, mv: f32) -> f32 {
+        let baseline = match self.value {
+            Some(baseline) if self.frozen => baseline,
+            Some(baseline) => baseline + self.alpha * (mv - baseline),
+            None => mv,
+        };
+        self.value = Some(baseline);
+        mv - baseline
+    }
+}
+
+/// Configuration of a [`PresenceDetector`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PresenceConfig {
+    /// Detection threshold as a multiple of the measured noise level. Lower values detect
+    /// farther approaches at the cost of more false detections.
+    pub sensitivity: f32,
+    /// Lower bound of the adaptive threshold, in mV.
+    pub min_threshold_mv: f32,
+    /// Weight of each new sample in the baseline and noise estimates, between 0 and 1. The
+    /// time constant is about `1 / baseline_alpha` samples.
+    pub baseline_alpha: f32,
+    /// Number of samples the presence is held after the signal last exceeded the threshold.
+    pub hold_samples: u16,
+}
+
+impl Default for PresenceConfig {
+    fn default() -> Self {
+        Self {
+            sensitivity: 6.0,
+            min_threshold_mv: 0.5,
+            baseline_alpha: 0.01,
+            hold_samples: 50,
+        }
+    }
+}
+
+/// Electrostatic presence and approach detector.
+///
+/// The `PresenceDetector` tracks the slow baseline of the signal and its noise level while
+/// nobody is around. A presence is reported when the deviation from the baseline exceeds an
+/// adaptive threshold, derived from the noise level; the baseline is frozen during a presence
+/// so a person standing still is not absorbed into it.
+///
+/// ```ignore
+/// let mut presence = PresenceDetector::new(PresenceConfig::default());
+/// let present = presence.update(sensor.ah_qvar_data_get().await?.mv);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PresenceDetector {
+    config: PresenceConfig,
+    baseline: Baseline,
+    noise_mv: f32,
+    hold: u16,
+}
+
+impl PresenceDetector {
+    /// Creates a detector; the first sample initializes the baseline.
+    pub const fn new(config: PresenceConfig) -> Self {
+        Self {
+            config,
+            baseline: Baseline::new(config.baseline_alpha),
+            noise_mv: 0.0,
+            hold: 0,
+        }
+    }
+
+    /// Returns the configuration.
+    pub const fn config(&self) -> PresenceConfig {
+        self.config
+    }
+
+    /// Updates the configuration, keeping the learned baseline.
+    pub fn set_config(&mut self, config: PresenceConfig) {
+        self.config = config;
+        self.baseline.set_alpha(config.baseline_alpha);
+    }
+
+    /// Clears the learned baseline and noise level.
+    pub fn reset(&mut self) {
+        self.baseline.reset();
+        self.baseline.resume();
+        self.noise_mv = 0.0;
+        self.hold = 0;
+    }
+
+    /// Returns `true` while a presence is reported.
+    pub const fn is_present(&self) -> bool {
+        self.hold > 0
+    }
+
+    /// Returns the current detection threshold, in mV.
+    pub fn threshold_mv(&self) -> f32 {
+        (self.config.sensitivity * self.noise_mv).max(self.config.min_threshold_mv)
+    }
+
+    /// Returns the learned baseline, in mV, or `None` before the first sample.
+    pub const fn baseline_mv(&self) -> Option<f32> {
+        self.baseline.value()
+    }
+
+    /// Feeds an AH/QVAR sample in mV and returns `true` while a presence is reported.
+    pub fn update(&mut self, mv: f32) -> bool {
+        let Some(baseline_mv) = self.baseline.value() else {
+            self.baseline.update(mv);
+            return false;
+        };
+
+        let deviation = (mv - baseline_mv).abs();
+        if deviation > self.threshold_mv() {
+            self.hold = self.config.hold_samples.max(1);
+        } else {
+            self.hold = self.hold.saturating_sub(1);
+        }
+
+        if self.is_present() {
+            self.baseline.freeze();
+        } else {
+            self.baseline.resume();
+            self.noise_mv += self.config.baseline_alpha * (deviation - self.noise_mv);
+        }
+        self.baseline.update(mv);
+        self.is_present()
+    }
+}
+
+/// Represents a calibration point of a [`LevelDetector`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LevelPoint {
+    /// The electrode is dry (empty tank).
+    Dry,
+    /// The electrode is fully wet (full tank).
+    Wet,
+}
+
+/// Liquid presence and level detector.
+///
+/// The `LevelDetector` learns the signal of a dry and of a fully wet electrode with
+/// [`LevelDetector::calibrate`], then maps the signal linearly onto a fill level between `0.0`
+/// (dry) and `1.0` (wet). Liquid presence is reported around the half level with a hysteresis
+/// band, so ripples on the surface do not toggle it.
+///
+/// ```ignore
+/// let mut level = LevelDetector::new(0.2);
+/// level.calibrate(LevelPoint::Dry, dry_samples.iter().copied());
+/// level.calibrate(LevelPoint::Wet, wet_samples.iter().copied());
+/// let wet = level.update(sensor.ah_qvar_data_get().await?.mv);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LevelDetector {
+    dry_mv: Option<f32>,
+    wet_mv: Option<f32>,
+    hysteresis: f32,
+    wet: bool,
+}
+
+impl LevelDetector {
+    /// Creates an uncalibrated detector.
+    ///
+    /// # Parameters
+    ///
+    /// * `hysteresis`: The width of the presence hysteresis band, as a fraction of the full level.
+    pub const fn new(hysteresis: f32) -> Self {
+        Self {
+            dry_mv: None,
+            wet_mv: None,
+            hysteresis,
+            wet: false,
+        }
+    }
+
+    /// Learns a calibration point from the mean of `samples`, in mV.
+    ///
+    /// Returns `false`, leaving the point unchanged, if `samples` is empty.
+    pub fn calibrate(&mut self, point: LevelPoint, samples: impl IntoIterator<Item = f32>) -> bool {
+        let (sum, count) = samples
+            .into_iter()
+            .fold((0.0, 0u32), |(sum, count), mv| (sum + mv, count + 1));
+        if count == 0 {
+            return false;
+        }
+        let mean = Some(sum / count as f32);
+        match point {
+            LevelPoint::Dry => self.dry_mv = mean,
+            LevelPoint::Wet => self.wet_mv = mean,
+        }
+        true
+    }
+
+    /// Returns the learned signal of a calibration point, in mV.
+    pub const fn baseline_mv(&self, point: LevelPoint) -> Option<f32> {
+        match point {
+            LevelPoint::Dry => self.dry_mv,
+            LevelPoint::Wet => self.wet_mv,
+        }
+    }
+
+    /// Returns `true` once both calibration points are learned and distinct.
+    pub fn is_calibrated(&self) -> bool {
+        matches!((self.dry_mv, self.wet_mv), (Some(dry), Some(wet)) if dry != wet)
+    }
+
+    /// Returns the fill level at the signal `mv`, clamped between `0.0` and `1.0`, or `None` if
+    /// the detector is not calibrated.
+    pub fn level(&self, mv: f32) -> Option<f32> {
+        match (self.dry_mv, self.wet_mv) {
+            (Some(dry), Some(wet)) if dry != wet => {
+                Some(((mv - dry) / (wet - dry)).clamp(0.0, 1.0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` while liquid presence is reported.
+    pub const fn is_wet(&self) -> bool {
+        self.wet
+    }
+
+    /// Feeds an AH/QVAR sample in mV and returns the liquid presence, or `None` if the
+    /// detector is not calibrated.
+    pub fn update(&mut self, mv: f32) -> Option<bool> {
+        let level = self.level(mv)?;
+        let half_band = self.hysteresis / 2.0;
+        self.wet = if self.wet {
+            level > 0.5 - half_band
+        } else {
+            level > 0.5 + half_band
+        };
+        Some(self.wet)
+    }
+}
+
+/// Zero-crossing frequency estimator.
+///
+/// The `FrequencyEstimator` counts the rising zero crossings of a signal without DC component
+/// (e.g. the output of [`Baseline::update`]) over a window of samples, and estimates the
+/// frequency from the time between the first and the last crossing of the window. A hysteresis
+/// band around zero keeps noise from producing spurious crossings. The sample rate must be at
+/// least twice the measured frequency.
+///
+/// ```ignore
+/// let mut hum = FrequencyEstimator::new(240.0, 240, 0.2);
+/// if let Some(hz) = hum.update(baseline.update(sample.mv)) { /* ... */ }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FrequencyEstimator {
+    rate_hz: f32,
+    window: u32,
+    hysteresis_mv: f32,
+    positive: Option<bool>,
+    index: u32,
+    first: Option<u32>,
+    last: u32,
+    crossings: u32,
+    frequency_hz: Option<f32>,
+}
+
+impl FrequencyEstimator {
+    /// Creates an estimator.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate_hz`: The sample rate, in Hz.
+    /// * `window`: The number of samples per estimate.
+    /// * `hysteresis_mv`: The half-width of the band around zero, in mV.
+    pub const fn new(rate_hz: f32, window: u32, hysteresis_mv: f32) -> Self {
+        Self {
+            rate_hz,
+            window,
+            hysteresis_mv,
+            positive: None,
+            index: 0,
+            first: None,
+            last: 0,
+            crossings: 0,
+            frequency_hz: None,
+        }
+    }
+
+    /// Returns the latest estimate, in Hz, or `None` if the last window held fewer than two
+    /// rising crossings.
+    pub const fn frequency_hz(&self) -> Option<f32> {
+        self.frequency_hz
+    }
+
+    /// Clears the current window and the latest estimate.
+    pub fn reset(&mut self) {
+        self.positive = None;
+        self.restart();
+        self.frequency_hz = None;
+    }
+
+    /// Feeds a sample in mV.
+    ///
+    /// Returns the new estimate, in Hz, when the sample completes a window with at least two
+    /// rising crossings.
+    pub fn update(&mut self, mv: f32) -> Option<f32> {
+        let positive = if mv > self.hysteresis_mv {
+            Some(true)
+        } else if mv < -self.hysteresis_mv {
+            Some(false)
+        } else {
+            self.positive
+        };
+
+        if self.positive == Some(false) && positive == Some(true) {
+            self.first.get_or_insert(self.index);
+            self.last = self.index;
+            self.crossings += 1;
+        }
+        self.positive = positive;
+        self.index += 1;
+
+        if self.index < self.window.max(1) {
+            return None;
+        }
+
+        let estimate = match self.first {
+            Some(first) if self.crossings >= 2 && self.last > first => {
+                Some((self.crossings - 1) as f32 * self.rate_hz / (self.last - first) as f32)
+            }
+            _ => None,
+        };
+        self.frequency_hz = estimate;
+        self.restart();
+        estimate
+    }
+
+    fn restart(&mut self) {
+        self.index = 0;
+        self.first = None;
+        self.last = 0;
+        self.crossings = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn baseline_tracks_steps_unless_frozen() {
+        let mut baseline = Baseline::with_time_constant(1.0, 1.0);
+        assert_eq!(baseline.alpha(), 0.5);
+        assert_eq!(baseline.value(), None);
+
+        // The first sample initializes the baseline
+        assert_eq!(baseline.update(10.0), 0.0);
+        assert_eq!(baseline.value(), Some(10.0));
+
+        // A step is absorbed with the time constant
+        assert_eq!(baseline.update(20.0), 5.0);
+        assert_eq!(baseline.update(20.0), 2.5);
+        assert_eq!(baseline.value(), Some(17.5));
+
+        baseline.freeze();
+        assert_eq!(baseline.update(30.0), 12.5);
+        assert_eq!(baseline.value(), Some(17.5));
+        baseline.resume();
+        assert_eq!(baseline.update(17.5), 0.0);
+
+        baseline.reset();
+        assert_eq!(baseline.update(-4.0), 0.0);
+        assert_eq!(baseline.value(), Some(-4.0));
+    }
+
+    #[test]
+    fn presence_holds_on_step_and_releases_after_glitch() {
+        let mut presence = PresenceDetector::new(PresenceConfig {
+            hold_samples: 5,
+            ..PresenceConfig::default()
+        });
+        let noise = |i: u32| if i.is_multiple_of(2) { 0.05 } else { -0.05 };
+        for i in 0..500 {
+            assert!(!presence.update(100.0 + noise(i)));
+        }
+        assert_eq!(presence.threshold_mv(), 0.5);
+
+        // An approach raises the presence and freezes the baseline while it lasts
+        let baseline_mv = presence.baseline_mv();
+        for i in 0..100 {
+            assert!(presence.update(110.0 + noise(i)));
+        }
+        assert_eq!(presence.baseline_mv(), baseline_mv);
+
+        // Leaving releases it once the hold time has elapsed
+        for i in 0..5 {
+            assert_eq!(presence.update(100.0 + noise(i)), i < 4);
+        }
+
+        // A single-sample glitch is held for the hold time only
+        assert!(presence.update(105.0));
+        for i in 0..5 {
+            assert_eq!(presence.update(100.0 + noise(i)), i < 4);
+        }
+        assert!(
+            presence
+                .baseline_mv()
+                .is_some_and(|mv| (mv - 100.0).abs() < 0.01)
+        );
+    }
+
+    #[test]
+    fn level_reports_wet_with_hysteresis() {
+        let mut level = LevelDetector::new(0.2);
+        assert_eq!(level.update(150.0), None);
+        assert!(!level.calibrate(LevelPoint::Dry, []));
+        assert!(level.calibrate(LevelPoint::Dry, [99.0, 101.0]));
+        assert!(!level.is_calibrated());
+        assert!(level.calibrate(LevelPoint::Wet, [200.0]));
+        assert!(level.is_calibrated());
+        assert_eq!(level.level(150.0), Some(0.5));
+        assert_eq!(level.level(250.0), Some(1.0));
+
+        // The presence toggles past the hysteresis band only
+        assert_eq!(level.update(155.0), Some(false));
+        assert_eq!(level.update(170.0), Some(true));
+        assert_eq!(level.update(145.0), Some(true));
+        assert_eq!(level.update(130.0), Some(false));
+        assert!(!level.is_wet());
+    }
+
+    #[test]
+    fn frequency_of_sine_is_estimated_per_window() {
+        let mut hum = FrequencyEstimator::new(240.0, 240, 0.2);
+        let sample = |i: u32| 5.0 * (TAU * 10.0 * i as f32 / 240.0).sin();
+        for i in 0..239 {
+            assert_eq!(hum.update(sample(i)), None);
+        }
+        let hz = hum.update(sample(239));
+        assert!(hz.is_some_and(|hz| (hz - 10.0).abs() < 0.01));
+        assert_eq!(hum.frequency_hz(), hz);
+
+        // A signal within the hysteresis band has no crossings
+        for _ in 0..239 {
+            assert_eq!(hum.update(0.1), None);
+        }
+        assert_eq!(hum.update(-0.1), None);
+        assert_eq!(hum.frequency_hz(), None);
+    }
+}
@@ -1,15 +1,18 @@
 use super::super::{
-    BusOperation, DelayNs, Error, Ilps22qs, RegisterOperation, SensorOperation, bisync,
+    BusOperation, Error, Ilps22qs, PRESSURE_SENSITIVITY_FS1260_LSB_PER_HPA,
+    PRESSURE_SENSITIVITY_FS4060_LSB_PER_HPA, RegisterOperation, SensorOperation, bisync,
     register::OnState,
 };
 
 use bitfield_struct::bitfield;
+use core::time::Duration;
 use derive_more::TryFrom;
 use st_mem_bank_macro::register;
 
 /// Represents the register addresses for device configuration and data retrieval.
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, TryFrom)]
+#[try_from(repr)]
 pub enum Reg {
     /// Address for the interrupt configuration register.
     InterruptCfg = 0x0B,
@@ -69,6 +72,42 @@ pub enum Reg {
     FifoDataOutPressH = 0x7A,
 }
 
+impl Reg {
+    /// Returns the datasheet name of the register.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Reg::InterruptCfg => "INTERRUPT_CFG",
+            Reg::ThsPL => "THS_P_L",
+            Reg::ThsPH => "THS_P_H",
+            Reg::IfCtrl => "IF_CTRL",
+            Reg::WhoAmI => "WHO_AM_I",
+            Reg::CtrlReg1 => "CTRL_REG1",
+            Reg::CtrlReg2 => "CTRL_REG2",
+            Reg::CtrlReg3 => "CTRL_REG3",
+            Reg::FifoCtrl => "FIFO_CTRL",
+            Reg::FifoWtm => "FIFO_WTM",
+            Reg::RefPL => "REF_P_L",
+            Reg::RefPH => "REF_P_H",
+            Reg::I3cIfCtrl => "I3C_IF_CTRL",
+            Reg::RpdsL => "RPDS_L",
+            Reg::RpdsH => "RPDS_H",
+            Reg::IntSource => "INT_SOURCE",
+            Reg::FifoStatus1 => "FIFO_STATUS1",
+            Reg::FifoStatus2 => "FIFO_STATUS2",
+            Reg::Status => "STATUS",
+            Reg::PressOutXl => "PRESS_OUT_XL",
+            Reg::PressOutL => "PRESS_OUT_L",
+            Reg::PressOutH => "PRESS_OUT_H",
+            Reg::TempOutL => "TEMP_OUT_L",
+            Reg::TempOutH => "TEMP_OUT_H",
+            Reg::AnalogicHubDisable => "ANALOGIC_HUB_DISABLE",
+            Reg::FifoDataOutPressXl => "FIFO_DATA_OUT_PRESS_XL",
+            Reg::FifoDataOutPressL => "FIFO_DATA_OUT_PRESS_L",
+            Reg::FifoDataOutPressH => "FIFO_DATA_OUT_PRESS_H",
+        }
+    }
+}
+
 /// Interrupt mode configuration register.
 ///
 /// Configuration options:
@@ -85,9 +124,9 @@ pub enum Reg {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::InterruptCfg, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::InterruptCfg, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct InterruptCfg {
     #[bits(1)]
     pub phe: u8,
@@ -118,9 +157,9 @@ pub struct InterruptCfg {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::ThsPL, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb))]
+#[register(address = Reg::ThsPL, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct ThsP {
     #[bits(15)]
     pub ths: u16,
@@ -142,9 +181,9 @@ pub struct ThsP {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::IfCtrl, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::IfCtrl, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct IfCtrl {
     #[bits(1, access = RO)]
     not_used_01: u8,
@@ -171,9 +210,9 @@ pub struct IfCtrl {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::CtrlReg1, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::CtrlReg1, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct CtrlReg1 {
     #[bits(3)]
     pub avg: u8,
@@ -197,9 +236,9 @@ pub struct CtrlReg1 {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::CtrlReg2, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::CtrlReg2, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct CtrlReg2 {
     #[bits(1)]
     pub oneshot: u8,
@@ -230,9 +269,9 @@ pub struct CtrlReg2 {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::CtrlReg3, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::CtrlReg3, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct CtrlReg3 {
     #[bits(1)]
     pub if_add_inc: u8,
@@ -258,9 +297,9 @@ pub struct CtrlReg3 {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::FifoCtrl, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::FifoCtrl, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct FifoCtrl {
     #[bits(2)]
     pub f_mode: u8,
@@ -282,9 +321,9 @@ pub struct FifoCtrl {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::FifoWtm, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::FifoWtm, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct FifoWtm {
     #[bits(7)]
     pub wtm: u8,
@@ -300,9 +339,9 @@ pub struct FifoWtm {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::RefPL, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb))]
+#[register(address = Reg::RefPL, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct RefP {
     #[bits(16, access = RO)]
     pub refp: u16,
@@ -316,9 +355,9 @@ pub struct RefP {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::I3cIfCtrl, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::I3cIfCtrl, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct I3cIfCtrl {
     #[bits(5, access = RO)]
     not_used_02: u8,
@@ -337,9 +376,9 @@ pub struct I3cIfCtrl {
 /// # Fields
 ///
 /// * `rpds` - The pressure offset calibration value as a 16-bit signed integer (read-only).
-#[register(address = Reg::RpdsL, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb))]
+#[register(address = Reg::RpdsL, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct Rpds {
     #[bits(16, access = RO)]
     pub rpds: i16,
@@ -357,9 +396,9 @@ pub struct Rpds {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::IntSource, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::IntSource, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct IntSource {
     #[bits(1, access = RO)]
     pub ph: u8,
@@ -381,9 +420,9 @@ pub struct IntSource {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::FifoStatus1, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::FifoStatus1, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct FifoStatus1 {
     #[bits(8, access = RO)]
     pub fss: u8,
@@ -399,9 +438,9 @@ pub struct FifoStatus1 {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::FifoStatus2, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::FifoStatus2, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct FifoStatus2 {
     #[bits(5, access = RO)]
     not_used_01: u8,
@@ -426,9 +465,9 @@ pub struct FifoStatus2 {
 /// The bit order for this struct can be configured using the `bit_order_msb` feature:
 /// * `Msb`: Most significant bit first.
 /// * `Lsb`: Least significant bit first (default).
-#[register(address = Reg::Status, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::Status, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct Status {
     #[bits(1, access = RO)]
     pub p_da: u8,
@@ -454,9 +493,9 @@ pub struct Status {
 /// # Fields
 ///
 /// * `pout` - The raw pressure output value as a 32-bit signed integer (read-only).
-#[register(address = Reg::PressOutXl, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u32, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u32, order = Lsb))]
+#[register(address = Reg::PressOutXl, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u32, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u32, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct PressOut {
     #[offset_before(8)]
     #[bits(32, access = RO)]
@@ -471,9 +510,9 @@ pub struct PressOut {
 /// # Fields
 ///
 /// * `tout` - The raw temperature output value as a 16-bit signed integer (read-only).
-#[register(address = Reg::TempOutL, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb))]
+#[register(address = Reg::TempOutL, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u16, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u16, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct TempOut {
     #[bits(16, access = RO)]
     pub tout: i16,
@@ -489,9 +528,9 @@ pub struct TempOut {
 /// # Fields
 ///
 /// * `fifo_p` - The raw FIFO pressure output value as a 32-bit signed integer (read-only).
-#[register(address = Reg::FifoDataOutPressXl, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u32, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u32, order = Lsb))]
+#[register(address = Reg::FifoDataOutPressXl, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u32, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u32, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct FifoDataOutPress {
     #[offset_before(8)]
     #[bits(32, access = RO)]
@@ -506,9 +545,9 @@ pub struct FifoDataOutPress {
 /// # Fields
 ///
 /// * `whoami` - The device identification value as an 8-bit unsigned integer (read-only).
-#[register(address = Reg::WhoAmI, access_type = "Ilps22qs<B, T, OnState>")]
-#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb))]
-#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb))]
+#[register(address = Reg::WhoAmI, access_type = "Ilps22qs<B, OnState>")]
+#[cfg_attr(feature = "bit_order_msb", bitfield(u8, order = Msb, defmt = cfg(feature = "defmt")))]
+#[cfg_attr(not(feature = "bit_order_msb"), bitfield(u8, order = Lsb, defmt = cfg(feature = "defmt")))]
 pub struct WhoAmI {
     #[bits(8, access = RO)]
     pub whoami: u8,
@@ -518,6 +557,7 @@ pub struct WhoAmI {
 ///
 /// This struct is used to specify and retrieve the desired bus operating mode, including the type of communication interface
 /// and the filter settings.
+#[derive(Clone, Copy, PartialEq, Default)]
 pub struct BusMode {
     /// The communication interface type.
     pub interface: Interface,
@@ -530,34 +570,41 @@ pub struct BusMode {
 /// This struct is used to store various status indicators that provide detailed insights into the device's
 /// current operational state. It includes information about reset status, boot status, data readiness,
 /// measurement completion, and reference operations.
+///
+/// # Migration
+///
+/// The indicators used to be `u8` flags. Code such as `if stat.drdy_pres == 1` becomes
+/// `if stat.drdy_pres`, and `stat.drdy_pres as u8` yields the previous numeric value.
+#[derive(Clone, Copy, Default, PartialEq)]
 pub struct Stat {
     /// Software reset status.
-    pub sw_reset: u8,
+    pub sw_reset: bool,
     /// Boot status.
-    pub boot: u8,
+    pub boot: bool,
     /// Data readiness for pressure.
-    pub drdy_pres: u8,
+    pub drdy_pres: bool,
     /// Data readiness for temperature.
-    pub drdy_temp: u8,
+    pub drdy_temp: bool,
     /// Overrun status for pressure data.
-    pub ovr_pres: u8,
+    pub ovr_pres: bool,
     /// Overrun status for temperature data.
-    pub ovr_temp: u8,
+    pub ovr_temp: bool,
     /// Measurement completion status.
-    pub end_meas: u8,
+    pub end_meas: bool,
     /// Reference operation completion status.
-    pub ref_done: u8,
+    pub ref_done: bool,
 }
 
 /// The `PinConf` struct represents the electrical configuration settings for the device's configurable pins.
 ///
 /// This struct is used to specify and retrieve the desired electrical settings for the device's pins,
 /// such as enabling or disabling pull-up resistors.
+#[derive(Clone, Copy, PartialEq, Default)]
 pub struct PinConf {
     /// Indicates whether the pull-up resistor for the SDA pin is enabled.
-    pub sda_pull_up: u8,
+    pub sda_pull_up: bool,
     /// Indicates whether the pull-up resistor for the CS pin is enabled.
-    pub cs_pull_up: u8,
+    pub cs_pull_up: bool,
 }
 
 /// Represents the status of all interrupt sources for a device.
@@ -565,23 +612,65 @@ pub struct PinConf {
 /// The `AllSources` struct provides detailed information about various interrupt conditions
 /// that can occur in the device. It includes indicators for data readiness, pressure thresholds,
 /// and FIFO conditions.
+///
+/// # Migration
+///
+/// The indicators used to be `u8` flags. Code such as `if sources.fifo_th > 0` becomes
+/// `if sources.fifo_th`, and `sources.fifo_th as u8` yields the previous numeric value.
+#[derive(Clone, Copy, Default, PartialEq)]
 pub struct AllSources {
     /// Data readiness indicator for pressure measurements.
-    pub drdy_pres: u8,
+    pub drdy_pres: bool,
     /// Data readiness indicator for temperature measurements.
-    pub drdy_temp: u8,
+    pub drdy_temp: bool,
     /// Indicator for over-pressure condition.
-    pub over_pres: u8,
+    pub over_pres: bool,
     /// Indicator for under-pressure condition.
-    pub under_pres: u8,
+    pub under_pres: bool,
     /// Indicator for pressure threshold condition.
-    pub thrsld_pres: u8,
+    pub thrsld_pres: bool,
     /// Indicator for FIFO full condition.
-    pub fifo_full: u8,
+    pub fifo_full: bool,
     /// Indicator for FIFO overrun condition.
-    pub fifo_ovr: u8,
+    pub fifo_ovr: bool,
     /// Indicator for FIFO threshold condition.
-    pub fifo_th: u8,
+    pub fifo_th: bool,
+}
+
+impl AllSources {
+    /// Returns the events signaled by the indicators, in [`Event`] declaration order.
+    pub fn events(&self) -> impl Iterator<Item = Event> + use<> {
+        [
+            (self.drdy_pres, Event::PressureReady),
+            (self.drdy_temp, Event::TemperatureReady),
+            (self.over_pres, Event::OverThreshold),
+            (self.under_pres, Event::UnderThreshold),
+            (self.fifo_th, Event::FifoWatermark),
+            (self.fifo_full, Event::FifoFull),
+            (self.fifo_ovr, Event::FifoOverrun),
+        ]
+        .into_iter()
+        .filter_map(|(active, event)| active.then_some(event))
+    }
+}
+
+/// Represents an event decoded from the device status.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Event {
+    /// A new pressure sample is available.
+    PressureReady,
+    /// A new temperature sample is available.
+    TemperatureReady,
+    /// The pressure crossed the threshold upwards.
+    OverThreshold,
+    /// The pressure crossed the threshold downwards.
+    UnderThreshold,
+    /// The FIFO reached its watermark.
+    FifoWatermark,
+    /// The FIFO is full.
+    FifoFull,
+    /// The FIFO overwrote unread samples.
+    FifoOverrun,
 }
 
 /// Represents the sensor conversion parameters.
@@ -589,10 +678,13 @@ pub struct AllSources {
 /// The `Md` struct encapsulates various settings related to sensor conversion, including output data rate (ODR),
 /// averaging, low-pass filter settings, full-scale mode, and interleaved mode configuration. These parameters
 /// are crucial for configuring the sensor's data processing and acquisition behavior.
-#[derive(Default)]
+///
+/// The value returned by `mode_get` can be compared against the one passed to `mode_set`, or
+/// saved, modified and written back.
+#[derive(Clone, Copy, PartialEq, Default)]
 pub struct Md {
     /// Specifies whether interleaved mode is enabled.
-    pub interleaved_mode: u8,
+    pub interleaved_mode: bool,
     /// Represents the full-scale mode setting, which determines the pressure range.
     pub fs: Fs,
     /// Specifies the output data rate setting, which affects the frequency of data acquisition.
@@ -606,6 +698,7 @@ pub struct Md {
 /// Represents AH/QVAR data retrieved from the sensor.
 ///
 /// It includes both raw and processed values, allowing for comprehensive analysis and application-specific processing.
+#[cfg(feature = "qvar")]
 pub struct AhQvarData {
     /// The converted AH/QVAR value in millivolts (mV)
     pub mv: f32,
@@ -615,15 +708,172 @@ pub struct AhQvarData {
     pub raw: i32,
 }
 
+/// Represents the action taken by a [`RecoveryPolicy`] once its error threshold is reached.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum RecoveryAction {
+    /// Reads WHO_AM_I again, restarting the count if the device answers.
+    #[default]
+    Reprobe,
+    /// Re-applies `Init::DrvRdy`, if applied before, and the conversion parameters kept by the
+    /// driver, restarting the count on success.
+    Reinit,
+    /// Reports `Error::Fatal` until a read succeeds, leaving the recovery to the application.
+    Fatal,
+}
+
+/// Represents the recovery policy applied by the read paths after consecutive bus errors.
+///
+/// The driver counts the consecutive bus errors of the read paths listed in
+/// [`Ilps22qs::recovery_policy_set`], and any successful read restarts the count. Once
+/// `max_errors` is reached the `action` is taken, so that an unattended device recovers from a
+/// transient bus lockup without the application handling every error. The read that triggered
+/// the action still returns its error: the sample is lost.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct RecoveryPolicy {
+    /// The number of consecutive bus errors triggering the action; `0` behaves as `1`.
+    pub max_errors: u8,
+    /// The action taken once `max_errors` is reached.
+    pub action: RecoveryAction,
+}
+
+/// Represents the schedule of the managed AH/QVAR mode.
+///
+/// While AH/QVAR is enabled, the output registers carry AH/QVAR samples instead of pressure. The
+/// `QvarSchedule` struct lets `ah_qvar_managed_get` interleave one pressure and temperature
+/// conversion every `qvar_samples` AH/QVAR samples, toggling the function transparently.
+#[cfg(feature = "qvar")]
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct QvarSchedule {
+    /// The number of AH/QVAR samples between two pressure samples.
+    pub qvar_samples: u16,
+    count: u16,
+    pressure_due: bool,
+}
+
+#[cfg(feature = "qvar")]
+impl QvarSchedule {
+    /// Creates a schedule taking a pressure sample every `qvar_samples` AH/QVAR samples.
+    pub const fn new(qvar_samples: u16) -> Self {
+        Self {
+            qvar_samples,
+            count: 0,
+            pressure_due: false,
+        }
+    }
+
+    /// Returns `true` if the next managed sample is a pressure sample.
+    pub const fn is_pressure_due(&self) -> bool {
+        self.pressure_due
+    }
+
+    /// Accounts for an AH/QVAR sample.
+    ///
+    /// Returns `true` if it was the last one before a pressure sample.
+    pub fn qvar_sampled(&mut self) -> bool {
+        self.count = self.count.saturating_add(1);
+        self.pressure_due = self.count >= self.qvar_samples;
+        self.pressure_due
+    }
+
+    /// Accounts for a pressure sample, restarting the AH/QVAR phase.
+    pub fn pressure_sampled(&mut self) {
+        self.count = 0;
+        self.pressure_due = false;
+    }
+}
+
+/// Represents a sample of the managed AH/QVAR mode, labeled with its source.
+#[cfg(feature = "qvar")]
+pub enum ManagedSample {
+    /// An AH/QVAR sample.
+    AhQvar(AhQvarData),
+    /// A pressure and temperature sample.
+    Pressure(Data),
+}
+
 /// Represents the FIFO operation mode settings for the device.
 ///
 /// The `FifoMd` struct encapsulates the configuration parameters for the FIFO (First-In, First-Out)
 /// operation mode.
+#[derive(Clone, Copy, PartialEq, Default)]
 pub struct FifoMd {
     /// Specifies the FIFO operation mode
     pub operation: Operation,
     /// Defines the watermark level for the FIFO buffer
-    pub watermark: u8,
+    pub watermark: Watermark,
+}
+
+/// Represents the FIFO watermark level.
+///
+/// The level is bounded by the FIFO depth: values that do not fit the 7-bit `WTM` field are
+/// rejected at construction instead of being silently truncated when written to the device.
+/// A level of zero disables the stop-on-watermark feature.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Watermark(u8);
+
+impl Watermark {
+    /// Highest watermark level accepted by the FIFO.
+    pub const MAX: u8 = 127;
+
+    /// Watermark disabled.
+    pub const DISABLED: Self = Self(0);
+
+    /// Creates a watermark level, returning `None` if `level` exceeds [`Watermark::MAX`].
+    pub const fn new(level: u8) -> Option<Self> {
+        if level <= Self::MAX {
+            Some(Self(level))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a watermark level checked at compile time.
+    ///
+    /// ```ignore
+    /// let watermark = Watermark::from_const::<32>();
+    /// ```
+    pub const fn from_const<const LEVEL: u8>() -> Self {
+        const { assert!(LEVEL <= Self::MAX, "watermark exceeds the FIFO depth") };
+        Self(LEVEL)
+    }
+
+    /// Returns the watermark level.
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+}
+
+/// Represents the FIFO fill levels observed by the driver.
+///
+/// The statistics are updated with the level read before each drain, so they show how full the
+/// FIFO gets between drains: a high-water mark close to the FIFO depth calls for a lower
+/// watermark or a faster polling rate, while a low average fill allows draining less often.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct FifoStats {
+    /// The number of recorded fill levels
+    pub drains: u32,
+    /// The sum of the recorded fill levels, in samples
+    pub samples: u64,
+    /// The highest recorded fill level, in samples
+    pub high_water: u8,
+}
+
+impl FifoStats {
+    /// Records the fill level of a drain.
+    pub fn record(&mut self, level: u8) {
+        self.drains = self.drains.saturating_add(1);
+        self.samples = self.samples.saturating_add(u64::from(level));
+        self.high_water = self.high_water.max(level);
+    }
+
+    /// Returns the average fill level, in samples, or `None` if no level was recorded.
+    #[cfg(feature = "float")]
+    pub fn average(&self) -> Option<f32> {
+        if self.drains == 0 {
+            return None;
+        }
+        Some(self.samples as f32 / self.drains as f32)
+    }
 }
 
 /// Represents data retrieved from the FIFO buffer.
@@ -631,6 +881,7 @@ pub struct FifoMd {
 /// The `FifoData` struct encapsulates the processed data from the FIFO buffer, including both raw and
 /// converted values. It supports the representation of pressure and AH/QVAR data, depending on the
 /// sensor configuration.
+#[cfg(feature = "float")]
 #[derive(Default, Clone, Copy)]
 pub struct FifoData {
     /// The converted pressure value in hectopascals (hPa)
@@ -639,36 +890,130 @@ pub struct FifoData {
     pub lsb: i32,
     /// The raw data value
     pub raw: i32,
+    /// The reconstructed acquisition time in microseconds, filled by `fifo_data_get_timestamped`
+    pub timestamp_us: u64,
 }
 
 /// Represents the configuration parameters for interrupt thresholds.
 ///
 /// The `IntThMd` struct encapsulates the settings for the device's wake-up and wake-up-to-sleep
 /// threshold events, which are based on pressure levels.
+#[derive(Clone, Copy, PartialEq, Default)]
 pub struct IntThMd {
     /// Specifies the pressure threshold value that triggers interrupt events.
     pub threshold: u16,
-    /// Indicates whether an over-threshold event is enabled
-    pub over_th: u8,
-    /// Indicates whether an under-threshold event is enabled (0 or 1).
-    pub under_th: u8,
+    /// Indicates whether an over-threshold event is enabled.
+    pub over_th: bool,
+    /// Indicates whether an under-threshold event is enabled.
+    pub under_th: bool,
+}
+
+/// Represents the complete interrupt configuration.
+///
+/// The `IntConfig` struct covers every setting of the INTERRUPT_CFG register: the threshold
+/// events, the latching of the interrupt requests and the reference the thresholds apply to.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct IntConfig {
+    /// Enables the interrupt on pressure above the threshold (PHE).
+    pub over_th: bool,
+    /// Enables the interrupt on pressure below the threshold (PLE).
+    pub under_th: bool,
+    /// Latches the interrupt requests into the `INT_SOURCE` register (LIR).
+    pub latched: bool,
+    /// The reference pressure the thresholds are compared to (AUTOZERO/AUTOREFP).
+    pub reference: IntReference,
+}
+
+/// Represents a pressure alarm with a hysteresis band.
+///
+/// The set point is expressed relative to the reference pressure captured by
+/// `threshold_hysteresis_set`: a positive value raises the alarm when the pressure rises above
+/// the reference, a negative one when it falls below. The alarm activates once the difference
+/// goes beyond `set_point_hpa ± hysteresis_hpa / 2` and clears only once it is back within the
+/// opposite edge of the band, so it does not chatter around the set point.
+///
+/// The device only detects the activation: while the alarm is inactive the threshold
+/// interrupt is armed on it, while it is active the interrupt is disarmed and the release is
+/// tracked on the samples passed to `threshold_hysteresis_update`.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct ThresholdHysteresis {
+    /// The alarm set point relative to the reference pressure, in hPa.
+    pub set_point_hpa: f32,
+    /// The width of the hysteresis band centered on the set point, in hPa.
+    pub hysteresis_hpa: f32,
+    active: bool,
+}
+
+#[cfg(feature = "float")]
+impl ThresholdHysteresis {
+    /// Creates an inactive alarm.
+    pub const fn new(set_point_hpa: f32, hysteresis_hpa: f32) -> Self {
+        Self {
+            set_point_hpa,
+            hysteresis_hpa,
+            active: false,
+        }
+    }
+
+    /// Returns `true` while the alarm is raised.
+    pub const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns the threshold interrupt configuration matching the alarm state.
+    pub fn int_th_md(&self, fs: Fs) -> IntThMd {
+        let activation_hpa = self.set_point_hpa.abs() + self.hysteresis_hpa / 2.0;
+        // Float to integer casts saturate: clamp to the 15-bit THS_P field
+        let threshold = (fs.sensitivity().hpa_to_reg16(activation_hpa) as u16).min(0x7FFF);
+
+        IntThMd {
+            threshold,
+            over_th: !self.active && self.set_point_hpa >= 0.0,
+            under_th: !self.active && self.set_point_hpa < 0.0,
+        }
+    }
+
+    /// Updates the alarm state with a pressure difference from the reference, in hPa.
+    ///
+    /// Returns `true` if the state changed.
+    pub fn update(&mut self, delta_hpa: f32) -> bool {
+        let half_band = self.hysteresis_hpa / 2.0;
+        // Distance from the reference in the direction of the alarm
+        let (distance, set_point) = if self.set_point_hpa >= 0.0 {
+            (delta_hpa, self.set_point_hpa)
+        } else {
+            (-delta_hpa, -self.set_point_hpa)
+        };
+
+        let active = if self.active {
+            distance > set_point - half_band
+        } else {
+            distance > set_point + half_band
+        };
+        let changed = active != self.active;
+        self.active = active;
+        changed
+    }
 }
 
 /// Represents the reference mode settings for wake-up and wake-up-to-sleep functionality.
 ///
 /// The `RefMd` struct encapsulates the configuration parameters for managing reference pressure levels,
 /// which are used to trigger wake-up and sleep events.
+#[derive(Clone, Copy, PartialEq, Default)]
 pub struct RefMd {
     /// Specifies how reference pressure levels are applied.
     pub apply_ref: ApplyRef,
     /// Indicates whether the device should obtain reference pressure levels, influencing how reference configurations are managed
-    pub get_ref: u8,
+    pub get_ref: bool,
 }
 
 /// Represents pressure data retrieved from the sensor.
 ///
 /// The `Pressure` struct encapsulates both raw and processed pressure data, providing a meaningful
 /// representation in hectopascals (hPa) for applications that require pressure measurements.
+#[cfg(feature = "float")]
 #[derive(Clone, Copy, Default)]
 pub struct Pressure {
     /// The converted pressure value in hectopascals (hPa).
@@ -681,6 +1026,7 @@ pub struct Pressure {
 ///
 /// The `Heat` struct encapsulates both raw and processed temperature data, providing a meaningful
 /// representation in degrees Celsius (°C) for applications that require temperature measurements.
+#[cfg(feature = "float")]
 #[derive(Clone, Copy, Default)]
 pub struct Heat {
     /// The converted temperature value in degrees Celsius (°C).
@@ -693,6 +1039,7 @@ pub struct Heat {
 ///
 /// The `AhQvar` struct encapsulates the least significant byte (LSB) of the AH/QVAR data, used for
 /// detailed data analysis in advanced sensing applications.
+#[cfg(feature = "float")]
 #[derive(Clone, Copy, Default)]
 pub struct AhQvar {
     /// The least significant byte (LSB) of the AH/QVAR data.
@@ -703,6 +1050,7 @@ pub struct AhQvar {
 ///
 /// The `Data` struct aggregates the processed sensor data, providing a comprehensive view of the
 /// sensor's output for applications that require multiple data types.
+#[cfg(feature = "float")]
 #[derive(Clone, Copy, Default)]
 pub struct Data {
     /// Contains the processed pressure data, including both raw and converted values.
@@ -713,6 +1061,345 @@ pub struct Data {
     pub ah_qvar: AhQvar,
 }
 
+/// Represents a sample with the metadata needed to detect dropped or repeated samples.
+///
+/// The sequence number is incremented by the driver for every new sample, wrapping around, so a
+/// consumer can tell samples apart after they went through queues or channels.
+#[derive(Clone, Copy, Default)]
+pub struct Sample<T> {
+    /// The sequence number of the sample; a stale sample keeps the number of the one it repeats.
+    pub seq: u32,
+    /// `true` if samples were overwritten before being read, i.e. samples were dropped before
+    /// this one.
+    pub overrun: bool,
+    /// `true` if no new sample was available, so `data` repeats the previous sample.
+    pub stale: bool,
+    /// The sample.
+    pub data: T,
+}
+
+/// Represents a conversion expressed in fixed point.
+///
+/// The `FixedData` struct is returned by `data_get_fixed` for control loops running entirely in
+/// fixed point: the values are computed from the output registers with integer arithmetic only.
+#[cfg(feature = "fixed")]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedData {
+    /// The pressure value in hectopascals (hPa).
+    pub pressure_hpa: fixed::types::I16F16,
+    /// The temperature value in degrees Celsius (°C).
+    pub temperature_deg_c: fixed::types::I16F16,
+}
+
+/// Represents a differential pressure reading relative to the AUTOZERO reference.
+///
+/// The `DiffPressure` struct is returned by `differential_get` once a zero reference has been
+/// captured with `differential_zero`. Positive values indicate a pressure above the reference.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct DiffPressure {
+    /// The pressure difference in hectopascals (hPa).
+    pub hpa: f32,
+    /// The pressure difference in pascals (Pa).
+    pub pa: f32,
+    /// The raw differential output value.
+    pub raw: i32,
+}
+
+/// Represents the raw output registers of a single conversion.
+///
+/// The `RawData` struct holds the values exactly as read from the device, without any floating
+/// point conversion, for applications forwarding samples to a host or running on FPU-less cores.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct RawData {
+    /// The raw pressure (or interleaved AH/QVAR) output, left-aligned on 32 bits.
+    pub pressure: i32,
+    /// The raw temperature output, in hundredths of a degree Celsius.
+    pub temperature: i16,
+}
+
+/// Represents the complete configuration of the device.
+///
+/// The `DeviceConfig` struct gathers every setting the driver can read back, so the device state
+/// can be saved with `config_snapshot` and written again with `config_restore`, e.g. after a
+/// software reset or a sensor power cycle.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct DeviceConfig {
+    /// Bus interface and antispike filter settings.
+    pub bus_mode: BusMode,
+    /// Pull-up settings of the interface pins.
+    pub pin_conf: PinConf,
+    /// Sensor conversion parameters.
+    pub md: Md,
+    /// FIFO operation mode and watermark.
+    pub fifo: FifoMd,
+    /// Whether interrupt requests are latched.
+    pub int_latched: bool,
+    /// Threshold interrupt configuration.
+    pub int_th: IntThMd,
+    /// Reference pressure configuration.
+    pub reference: RefMd,
+    /// Pressure offset (RPDS register).
+    pub opc: i16,
+    /// Whether the AH/QVAR function is enabled.
+    #[cfg(feature = "qvar")]
+    pub ah_qvar_en: bool,
+}
+
+/// Represents the raw content of the configuration registers.
+///
+/// The `DeviceConfigDebug` struct is read with `config_debug_get` and is meant for diagnostics
+/// dumps, e.g. in remote support of deployed units: its `Debug` output (and its `defmt::Format`
+/// output with the `defmt` feature) lists every register with its fields decoded, such as
+/// `ctrl_reg2: CtrlReg2 { oneshot: 0, .., fs_mode: 1, boot: 0 }`. Two dumps can be compared
+/// with `diff`. Use [`DeviceConfig`] to save and restore the configuration.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeviceConfigDebug {
+    /// INTERRUPT_CFG register.
+    pub interrupt_cfg: InterruptCfg,
+    /// THS_P_L and THS_P_H registers.
+    pub ths_p: ThsP,
+    /// IF_CTRL register.
+    pub if_ctrl: IfCtrl,
+    /// WHO_AM_I register.
+    pub who_am_i: WhoAmI,
+    /// CTRL_REG1 register.
+    pub ctrl_reg1: CtrlReg1,
+    /// CTRL_REG2 register.
+    pub ctrl_reg2: CtrlReg2,
+    /// CTRL_REG3 register.
+    pub ctrl_reg3: CtrlReg3,
+    /// FIFO_CTRL register.
+    pub fifo_ctrl: FifoCtrl,
+    /// FIFO_WTM register.
+    pub fifo_wtm: FifoWtm,
+    /// REF_P_L and REF_P_H registers.
+    pub ref_p: RefP,
+    /// I3C_IF_CTRL register.
+    pub i3c_if_ctrl: I3cIfCtrl,
+    /// RPDS_L and RPDS_H registers.
+    pub rpds: Rpds,
+}
+
+/// Represents a user-supplied temperature compensation of the pressure output.
+///
+/// The `TempCompensation` struct models the residual pressure drift of a given unit as linear in
+/// the die temperature. The corrected pressure is
+/// `hpa - coefficient * (deg_c - reference_deg_c)`.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct TempCompensation {
+    /// Pressure drift in hectopascals per degree Celsius (hPa/°C).
+    pub coefficient: f32,
+    /// Temperature in degrees Celsius (°C) at which no correction is applied.
+    pub reference_deg_c: f32,
+}
+
+#[cfg(feature = "float")]
+impl TempCompensation {
+    /// Returns `hpa` corrected for the temperature `deg_c`.
+    pub fn apply(&self, hpa: f32, deg_c: f32) -> f32 {
+        hpa - self.coefficient * (deg_c - self.reference_deg_c)
+    }
+}
+
+/// Represents a user correction of a converted value.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, Debug)]
+pub enum Correction {
+    /// Polynomial `c[0] + c[1] * x + c[2] * x^2 + c[3] * x^3` of the converted value `x`.
+    Polynomial([f32; 4]),
+    /// Function of the converted value and of the die temperature in degrees Celsius (°C).
+    ///
+    /// For the temperature correction, both arguments are the converted temperature.
+    Function(fn(f32, f32) -> f32),
+}
+
+#[cfg(feature = "float")]
+impl Correction {
+    /// Returns `value` corrected, with `deg_c` the die temperature.
+    pub fn apply(&self, value: f32, deg_c: f32) -> f32 {
+        match self {
+            Self::Polynomial(coefficients) => coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |acc, coefficient| acc * value + coefficient),
+            Self::Function(function) => function(value, deg_c),
+        }
+    }
+}
+
+/// Represents the per-unit corrections applied by the driver to converted values.
+///
+/// The `UserCorrection` struct holds the characterization of a unit, e.g. a polynomial fitted at
+/// factory test, applied by [`Ilps22qs::data_get`] right after the conversion, before the
+/// temperature compensation and the [`Calibration`] gain/offset. The temperature is corrected
+/// first, so the pressure correction receives the corrected temperature.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct UserCorrection {
+    /// Correction of the pressure in hectopascals (hPa), or `None` to leave it untouched.
+    pub pressure: Option<Correction>,
+    /// Correction of the temperature in degrees Celsius (°C), or `None` to leave it untouched.
+    pub temperature: Option<Correction>,
+}
+
+/// Represents the calibration of a unit, as determined at end-of-line test.
+///
+/// The `Calibration` struct gathers the device-side one-point calibration offset and the
+/// software-side corrections applied by the driver, so that they can be stored in MCU flash with
+/// [`Calibration::to_le_bytes`] and re-applied on boot with [`Calibration::apply`].
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Calibration {
+    /// Pressure offset written to the `RPDS` registers (see [`Ilps22qs::opc_set`]).
+    pub rpds: i16,
+    /// Gain applied to converted pressure values.
+    pub pressure_gain: f32,
+    /// Offset in hectopascals (hPa) added to converted pressure values after the gain.
+    pub pressure_offset_hpa: f32,
+    /// Gain applied to converted AH/QVAR values.
+    pub qvar_gain: f32,
+    /// Sea-level reference pressure in hectopascals (hPa).
+    pub sea_level_hpa: f32,
+}
+
+#[cfg(feature = "float")]
+impl Calibration {
+    /// Calibration leaving the device output untouched.
+    pub const DEFAULT: Self = Self {
+        rpds: 0,
+        pressure_gain: 1.0,
+        pressure_offset_hpa: 0.0,
+        qvar_gain: 1.0,
+        sea_level_hpa: 1013.25,
+    };
+
+    /// Size in bytes of the serialized calibration.
+    pub const SIZE: usize = 18;
+
+    /// Returns `hpa` corrected by the pressure gain and offset.
+    pub fn pressure_correct(&self, hpa: f32) -> f32 {
+        hpa * self.pressure_gain + self.pressure_offset_hpa
+    }
+
+    /// Returns the calibration with the pressure gain and offset fitted to two points.
+    ///
+    /// The measured pressures are those reported with `self` in place, so the fit refines the
+    /// current gain and offset rather than replacing them, and can be repeated. Returns `None`
+    /// if the points do not define an increasing line, e.g. when both were measured at the same
+    /// pressure.
+    pub fn two_point(&self, low: CalibrationPoint, high: CalibrationPoint) -> Option<Self> {
+        let gain =
+            (high.reference_hpa - low.reference_hpa) / (high.measured_hpa - low.measured_hpa);
+        let offset_hpa = low.reference_hpa - gain * low.measured_hpa;
+        if !gain.is_finite() || !offset_hpa.is_finite() || gain <= 0.0 {
+            return None;
+        }
+
+        Some(Self {
+            pressure_gain: self.pressure_gain * gain,
+            pressure_offset_hpa: self.pressure_offset_hpa * gain + offset_hpa,
+            ..*self
+        })
+    }
+
+    /// Serializes the calibration in little-endian byte order.
+    pub fn to_le_bytes(&self) -> [u8; Self::SIZE] {
+        let mut buf = [0; Self::SIZE];
+        let [rpds_lo, rpds_hi, rest @ ..] = &mut buf;
+        [*rpds_lo, *rpds_hi] = self.rpds.to_le_bytes();
+        let values = [
+            self.pressure_gain,
+            self.pressure_offset_hpa,
+            self.qvar_gain,
+            self.sea_level_hpa,
+        ];
+        for (chunk, value) in rest.chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        buf
+    }
+
+    /// Deserializes a calibration produced by [`Calibration::to_le_bytes`].
+    pub fn from_le_bytes(buf: &[u8; Self::SIZE]) -> Self {
+        let [rpds_lo, rpds_hi, rest @ ..] = buf;
+        let rpds = i16::from_le_bytes([*rpds_lo, *rpds_hi]);
+
+        let mut values = [0.0f32; 4];
+        for (value, chunk) in values.iter_mut().zip(rest.chunks_exact(4)) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(chunk);
+            *value = f32::from_le_bytes(bytes);
+        }
+        let [pressure_gain, pressure_offset_hpa, qvar_gain, sea_level_hpa] = values;
+        Self {
+            rpds,
+            pressure_gain,
+            pressure_offset_hpa,
+            qvar_gain,
+            sea_level_hpa,
+        }
+    }
+
+    /// Applies the calibration to the sensor.
+    ///
+    /// Writes the `RPDS` offset to the device and installs the software corrections used by
+    /// subsequent conversions.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful writing of the offset register.
+    #[bisync]
+    pub async fn apply<B: BusOperation>(
+        &self,
+        sensor: &mut Ilps22qs<B, OnState>,
+    ) -> Result<(), Error<B::Error>> {
+        sensor.opc_set(self.rpds).await?;
+        sensor.calibration = *self;
+        Ok(())
+    }
+
+    /// Extracts the calibration currently in use by the sensor.
+    ///
+    /// Reads the `RPDS` offset back from the device and combines it with the software
+    /// corrections installed by [`Calibration::apply`].
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the offset register.
+    #[bisync]
+    pub async fn extract<B: BusOperation>(
+        sensor: &mut Ilps22qs<B, OnState>,
+    ) -> Result<Self, Error<B::Error>> {
+        let rpds = sensor.opc_get().await?;
+        Ok(Self {
+            rpds,
+            ..sensor.calibration
+        })
+    }
+}
+
+#[cfg(feature = "float")]
+impl Default for Calibration {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Represents a pressure measured by the sensor at a known reference pressure.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct CalibrationPoint {
+    /// Pressure reported by the driver, in hectopascals (hPa).
+    pub measured_hpa: f32,
+    /// Reference pressure applied to the device, in hectopascals (hPa).
+    pub reference_hpa: f32,
+}
+
 /// Represents the communication interface mode for the device.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom)]
@@ -761,6 +1448,80 @@ pub enum Fs {
     _4060hpa = 0x01,
 }
 
+impl Fs {
+    /// Returns the pressure sensitivity of the full scale.
+    pub const fn sensitivity(self) -> Sensitivity {
+        Sensitivity::from_fs(self)
+    }
+}
+
+/// Pressure sensitivity of a full scale, the single source of the FS-dependent scale factors.
+///
+/// The device reports pressure with different resolutions: the left-aligned 32-bit raw
+/// values returned by the driver, and the 16-bit THS_P, REF_P and RPDS registers, which hold
+/// the upper bits of the 24-bit output. Every conversion between hPa and one of these
+/// representations goes through this type, so none of them can miss the 4060 hPa scale.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sensitivity {
+    lsb_per_hpa: u32,
+}
+
+impl Sensitivity {
+    /// Returns the sensitivity of the full scale `fs`.
+    pub const fn from_fs(fs: Fs) -> Self {
+        let lsb_per_hpa = match fs {
+            Fs::_1260hpa => PRESSURE_SENSITIVITY_FS1260_LSB_PER_HPA,
+            Fs::_4060hpa => PRESSURE_SENSITIVITY_FS4060_LSB_PER_HPA,
+        };
+        Self { lsb_per_hpa }
+    }
+
+    /// Returns the sensitivity of the 24-bit output, in LSB/hPa.
+    pub const fn lsb_per_hpa(self) -> u32 {
+        self.lsb_per_hpa
+    }
+
+    /// Returns the sensitivity of the left-aligned 32-bit raw values, in LSB/hPa.
+    pub const fn raw_lsb_per_hpa(self) -> u32 {
+        self.lsb_per_hpa << 8
+    }
+
+    /// Returns the sensitivity of the 16-bit THS_P, REF_P and RPDS registers, in LSB/hPa.
+    pub const fn reg16_lsb_per_hpa(self) -> u32 {
+        self.lsb_per_hpa >> 8
+    }
+
+    /// Returns the right shift turning a raw value into a fixed-point value with 16
+    /// fractional bits.
+    pub const fn raw_to_fixed_shift(self) -> u32 {
+        self.raw_lsb_per_hpa().trailing_zeros() - 16
+    }
+
+    /// Converts a left-aligned 32-bit raw value to hPa.
+    #[cfg(feature = "float")]
+    pub fn raw_to_hpa(self, raw: i32) -> f32 {
+        raw as f32 / self.raw_lsb_per_hpa() as f32
+    }
+
+    /// Converts a value of the 16-bit THS_P, REF_P or RPDS registers to hPa.
+    #[cfg(feature = "float")]
+    pub fn reg16_to_hpa(self, value: i32) -> f32 {
+        value as f32 / self.reg16_lsb_per_hpa() as f32
+    }
+
+    /// Converts hPa to the LSB of the 16-bit THS_P, REF_P and RPDS registers, without rounding.
+    #[cfg(feature = "float")]
+    pub fn hpa_to_reg16(self, hpa: f32) -> f32 {
+        hpa * self.reg16_lsb_per_hpa() as f32
+    }
+}
+
+impl From<Fs> for Sensitivity {
+    fn from(fs: Fs) -> Self {
+        Self::from_fs(fs)
+    }
+}
+
 /// Represents the output data rate (ODR) settings for the sensor.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom)]
@@ -811,6 +1572,197 @@ pub enum Avg {
     _512 = 7,
 }
 
+impl Odr {
+    /// Continuous output data rates, in ascending order.
+    #[cfg(feature = "float")]
+    const CONTINUOUS: [Odr; 8] = [
+        Odr::_1hz,
+        Odr::_4hz,
+        Odr::_10hz,
+        Odr::_25hz,
+        Odr::_50hz,
+        Odr::_75hz,
+        Odr::_100hz,
+        Odr::_200hz,
+    ];
+
+    /// Returns the output data rate in Hz, or `0.0` in one-shot mode.
+    #[cfg(feature = "float")]
+    pub const fn hz(self) -> f32 {
+        match self {
+            Odr::OneShot => 0.0,
+            Odr::_1hz => 1.0,
+            Odr::_4hz => 4.0,
+            Odr::_10hz => 10.0,
+            Odr::_25hz => 25.0,
+            Odr::_50hz => 50.0,
+            Odr::_75hz => 75.0,
+            Odr::_100hz => 100.0,
+            Odr::_200hz => 200.0,
+        }
+    }
+
+    /// Returns the output data period in microseconds, or `None` in one-shot mode.
+    ///
+    /// The 75 Hz period is rounded down to the microsecond.
+    pub const fn period_us(self) -> Option<u32> {
+        match self {
+            Odr::OneShot => None,
+            Odr::_1hz => Some(1_000_000),
+            Odr::_4hz => Some(250_000),
+            Odr::_10hz => Some(100_000),
+            Odr::_25hz => Some(40_000),
+            Odr::_50hz => Some(20_000),
+            Odr::_75hz => Some(13_333),
+            Odr::_100hz => Some(10_000),
+            Odr::_200hz => Some(5_000),
+        }
+    }
+}
+
+impl Avg {
+    /// Returns the number of internal conversions averaged into each output sample.
+    pub const fn samples(self) -> u16 {
+        4 << (self as u16)
+    }
+
+    /// Returns the typical duration of an averaged conversion, in microseconds.
+    ///
+    /// The values are typical figures for each AVG setting, to be kept in sync with the
+    /// conversion time given in the datasheet; timeouts derived from them should include some
+    /// margin.
+    pub const fn conversion_time_us(self) -> u32 {
+        match self {
+            Avg::_4 => 244,
+            Avg::_8 => 388,
+            Avg::_16 => 676,
+            Avg::_32 => 1_252,
+            Avg::_64 => 2_404,
+            Avg::_128 => 4_708,
+            Avg::_256 => 9_316,
+            Avg::_512 => 18_532,
+        }
+    }
+
+    /// Returns the typical supply current at 1 Hz ODR, in µA.
+    ///
+    /// One value per AVG setting, to be kept in sync with the current consumption table of the
+    /// datasheet, which is given at 1 Hz ODR.
+    #[cfg(feature = "float")]
+    const fn supply_current_1hz_ua(self) -> f32 {
+        match self {
+            Avg::_4 => 1.72,
+            Avg::_8 => 2.44,
+            Avg::_16 => 3.88,
+            Avg::_32 => 6.76,
+            Avg::_64 => 12.52,
+            Avg::_128 => 24.04,
+            Avg::_256 => 47.08,
+            Avg::_512 => 93.16,
+        }
+    }
+
+    /// Returns the typical RMS pressure noise with the 1260 hPa full scale and the low-pass
+    /// filter disabled, in Pa.
+    ///
+    /// One value per AVG setting, to be kept in sync with the noise table of the datasheet.
+    #[cfg(feature = "float")]
+    const fn pressure_noise_rms_pa(self) -> f32 {
+        match self {
+            Avg::_4 => 2.6,
+            Avg::_8 => 1.9,
+            Avg::_16 => 1.4,
+            Avg::_32 => 1.0,
+            Avg::_64 => 0.7,
+            Avg::_128 => 0.5,
+            Avg::_256 => 0.4,
+            Avg::_512 => 0.34,
+        }
+    }
+}
+
+/// Typical supply current in power-down, in µA.
+#[cfg(feature = "float")]
+const SUPPLY_CURRENT_POWER_DOWN_UA: f32 = 1.0;
+
+impl Md {
+    /// Returns the typical supply current for the configured ODR and averaging, in µA.
+    ///
+    /// The current at 1 Hz is looked up per AVG setting. Each output sample takes the same
+    /// charge whatever the ODR, so the part above the power-down current is scaled by the ODR.
+    /// In one-shot mode only the power-down current is returned. Actual values vary from unit to
+    /// unit and with supply voltage and temperature.
+    #[cfg(feature = "float")]
+    pub fn supply_current_ua(&self) -> f32 {
+        let per_sample_ua = self.avg.supply_current_1hz_ua() - SUPPLY_CURRENT_POWER_DOWN_UA;
+        SUPPLY_CURRENT_POWER_DOWN_UA + per_sample_ua * self.odr.hz()
+    }
+
+    /// Returns the typical time needed by a one-shot conversion with the configured averaging.
+    ///
+    /// After `trigger_sw`, waiting this long before reading the output avoids worst-case delays.
+    /// Since the value is typical, polling the data-ready flag afterwards is still advisable.
+    pub const fn one_shot_duration(&self) -> Duration {
+        Duration::from_micros(self.avg.conversion_time_us() as u64)
+    }
+
+    /// Returns the typical RMS pressure noise for the configured averaging and filtering, in Pa.
+    ///
+    /// The noise is looked up per AVG setting for the 1260 hPa full scale; the 4060 hPa full
+    /// scale halves the resolution and is accounted as twice the noise. White noise scales with
+    /// the square root of the bandwidth, so the low-pass filter, narrowing it from ODR/2 to ODR/4
+    /// or ODR/9, divides the noise by √2 or by 3/√2. Actual noise depends on the unit and on the
+    /// environment, so the result is meant to compare configurations against a resolution target
+    /// rather than as a guaranteed figure.
+    #[cfg(feature = "float")]
+    pub fn pressure_noise_rms_pa(&self) -> f32 {
+        let noise = self.avg.pressure_noise_rms_pa();
+        let lpf = match self.lpf {
+            Lpf::Disable => 1.0,
+            Lpf::OdrDiv4 => core::f32::consts::FRAC_1_SQRT_2,
+            Lpf::OdrDiv9 => core::f32::consts::SQRT_2 / 3.0,
+        };
+        // The noise in hPa scales with the LSB size
+        let fs = Fs::_1260hpa.sensitivity().lsb_per_hpa() as f32
+            / self.fs.sensitivity().lsb_per_hpa() as f32;
+
+        noise * lpf * fs
+    }
+
+    /// Selects the ODR/AVG pair fitting a current budget and a signal bandwidth.
+    ///
+    /// The slowest ODR whose Nyquist frequency covers `bandwidth_hz` is chosen, together with the
+    /// largest averaging (lowest noise) that keeps [`Md::supply_current_ua`] within `budget_ua`
+    /// and whose conversion fits in half of the ODR period. The remaining fields are taken from
+    /// `self`.
+    ///
+    /// # Parameters
+    ///
+    /// * `budget_ua`: The available supply current, in µA.
+    /// * `bandwidth_hz`: The highest signal frequency to be captured, in Hz.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Md>`: The selected configuration, or `None` if no pair satisfies both
+    ///   requirements.
+    #[cfg(feature = "float")]
+    pub fn for_power_budget(&self, budget_ua: f32, bandwidth_hz: f32) -> Option<Md> {
+        let odr = Odr::CONTINUOUS
+            .into_iter()
+            .find(|odr| odr.hz() >= 2.0 * bandwidth_hz)?;
+        let half_period_us = 500_000.0 / odr.hz();
+
+        (0..=Avg::_512 as u8)
+            .rev()
+            .filter_map(|avg| Avg::try_from(avg).ok())
+            .map(|avg| Md { odr, avg, ..*self })
+            .find(|md| {
+                md.supply_current_ua() <= budget_ua
+                    && md.avg.conversion_time_us() as f32 <= half_period_us
+            })
+    }
+}
+
 /// Represents the low-pass filter settings for the sensor.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom)]
@@ -845,6 +1797,20 @@ pub enum Operation {
     BypassToFifo = 5,
 }
 
+/// Represents the reference pressure used by the threshold interrupts.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum IntReference {
+    /// The thresholds are compared to the absolute pressure; any stored reference is reset.
+    #[default]
+    Absolute,
+    /// AUTOZERO: the current pressure is stored as reference and subtracted from both the
+    /// output registers and the interrupt comparison.
+    AutoZero,
+    /// AUTOREFP: the current pressure is stored as reference and subtracted from the interrupt
+    /// comparison only, the output registers keep the absolute pressure.
+    AutoRefP,
+}
+
 /// Represents the application of reference pressure levels for wake-up and wake-up-to-sleep functionality.
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Default, TryFrom)]
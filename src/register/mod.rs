@@ -1,4 +1,7 @@
 pub mod main;
+mod view;
+
+pub use view::{RegisterChange, RegisterView};
 
 pub trait SensorState {}
 
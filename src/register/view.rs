@@ -0,0 +1,305 @@
+//! Human-readable formatting and comparison of register values.
+
+use super::main::*;
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Register value formatted with its fields decoded.
+///
+/// The `Display` implementation produces lines such as
+/// `CTRL_REG2 (0x11) = 0x40: oneshot=0 swreset=0 bdu=0 en_lpfp=0 lfpf_cfg=0 fs_mode=1 boot=0`,
+/// for diagnostics dumps. The fields are taken from the register bitfield definitions, without
+/// the reserved bits. Registers holding a byte of a wider value, such as `PRESS_OUT_L`, are
+/// printed without fields.
+///
+/// ```ignore
+/// let mut value = [0];
+/// sensor.read_from_register(Reg::CtrlReg2 as u8, &mut value)?;
+/// writeln!(uart, "{}", RegisterView::new(Reg::CtrlReg2, value[0]))?;
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct RegisterView {
+    /// The register.
+    pub reg: Reg,
+    /// The register value.
+    pub value: u8,
+}
+
+impl RegisterView {
+    /// Creates the view of `value` read from `reg`.
+    pub const fn new(reg: Reg, value: u8) -> Self {
+        Self { reg, value }
+    }
+}
+
+impl Display for RegisterView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let reg = self.reg;
+        write!(
+            f,
+            "{} (0x{:02X}) = 0x{:02X}",
+            reg.name(),
+            reg as u8,
+            self.value
+        )?;
+
+        let mut separator = ": ";
+        for_each_field(reg, self.value, &mut |name, value| {
+            write!(f, "{separator}{name}={value}")?;
+            separator = " ";
+            Ok(())
+        })
+    }
+}
+
+/// Register whose value differs between two snapshots, created with [`DeviceConfigDebug::diff`].
+///
+/// The `Display` implementation lists the changed fields, as in
+/// `CTRL_REG1 (0x10) = 0x22 -> 0x2A: odr=4->5`. Registers holding a byte of a wider value are
+/// printed with their values only.
+#[derive(Clone, Copy, PartialEq)]
+pub struct RegisterChange {
+    /// The register.
+    pub reg: Reg,
+    /// The value in the reference snapshot.
+    pub old: u8,
+    /// The value in the compared snapshot.
+    pub new: u8,
+}
+
+impl Display for RegisterChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let reg = self.reg;
+        write!(
+            f,
+            "{} (0x{:02X}) = 0x{:02X} -> 0x{:02X}",
+            reg.name(),
+            reg as u8,
+            self.old,
+            self.new
+        )?;
+
+        let mut old = FieldList::new();
+        for_each_field(reg, self.old, &mut |name, value| {
+            old.push(name, value);
+            Ok(())
+        })?;
+
+        let mut separator = ": ";
+        for_each_field(reg, self.new, &mut |name, new| match old.get(name) {
+            Some(old) if old != new => {
+                write!(f, "{separator}{name}={old}->{new}")?;
+                separator = " ";
+                Ok(())
+            }
+            _ => Ok(()),
+        })
+    }
+}
+
+impl DeviceConfigDebug {
+    /// Returns the registers of the snapshot, in address order.
+    ///
+    /// Registers wider than a byte are returned as their `_L` and `_H` halves.
+    pub fn registers(&self) -> [RegisterView; 15] {
+        let [ths_p_l, ths_p_h] = self.ths_p.into_bits().to_le_bytes();
+        let [ref_p_l, ref_p_h] = self.ref_p.into_bits().to_le_bytes();
+        let [rpds_l, rpds_h] = self.rpds.into_bits().to_le_bytes();
+        [
+            RegisterView::new(Reg::InterruptCfg, self.interrupt_cfg.into_bits()),
+            RegisterView::new(Reg::ThsPL, ths_p_l),
+            RegisterView::new(Reg::ThsPH, ths_p_h),
+            RegisterView::new(Reg::IfCtrl, self.if_ctrl.into_bits()),
+            RegisterView::new(Reg::WhoAmI, self.who_am_i.into_bits()),
+            RegisterView::new(Reg::CtrlReg1, self.ctrl_reg1.into_bits()),
+            RegisterView::new(Reg::CtrlReg2, self.ctrl_reg2.into_bits()),
+            RegisterView::new(Reg::CtrlReg3, self.ctrl_reg3.into_bits()),
+            RegisterView::new(Reg::FifoCtrl, self.fifo_ctrl.into_bits()),
+            RegisterView::new(Reg::FifoWtm, self.fifo_wtm.into_bits()),
+            RegisterView::new(Reg::RefPL, ref_p_l),
+            RegisterView::new(Reg::RefPH, ref_p_h),
+            RegisterView::new(Reg::I3cIfCtrl, self.i3c_if_ctrl.into_bits()),
+            RegisterView::new(Reg::RpdsL, rpds_l),
+            RegisterView::new(Reg::RpdsH, rpds_h),
+        ]
+    }
+
+    /// Compares the snapshot with a later one, returning the registers that changed.
+    ///
+    /// This localizes configuration drift, e.g. between a dump taken on a unit that worked
+    /// before a firmware update and one taken after it.
+    ///
+    /// ```ignore
+    /// let before = sensor.config_debug_get()?;
+    /// // ...
+    /// for change in before.diff(&sensor.config_debug_get()?) {
+    ///     writeln!(uart, "{change}")?;
+    /// }
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// * `other`: The snapshot to compare with `self`.
+    ///
+    /// # Returns
+    ///
+    /// * `impl Iterator<Item = RegisterChange>`: The changed registers, in address order.
+    pub fn diff(&self, other: &Self) -> impl Iterator<Item = RegisterChange> + use<> {
+        self.registers()
+            .into_iter()
+            .zip(other.registers())
+            .filter(|(old, new)| old.value != new.value)
+            .map(|(old, new)| RegisterChange {
+                reg: old.reg,
+                old: old.value,
+                new: new.value,
+            })
+    }
+}
+
+/// Prefix of the reserved fields of the bitfield definitions.
+const RESERVED: &str = "not_used";
+
+/// Longest field name of the bitfield definitions.
+const NAME_LEN: usize = 24;
+
+/// Calls `visit` with the name and value of every field of `value` read from `reg`.
+///
+/// The fields are taken from the `Debug` output of the register bitfield, in bit order, without
+/// the reserved ones. Registers holding a byte of a wider value have no field.
+fn for_each_field(
+    reg: Reg,
+    value: u8,
+    visit: &mut dyn FnMut(&str, u32) -> fmt::Result,
+) -> fmt::Result {
+    let mut fields = Fields {
+        visit,
+        state: State::Header,
+        name: [0; NAME_LEN],
+        name_len: 0,
+        value: 0,
+    };
+    match reg {
+        Reg::InterruptCfg => write!(fields, "{:?}", InterruptCfg::from_bits(value)),
+        Reg::IfCtrl => write!(fields, "{:?}", IfCtrl::from_bits(value)),
+        Reg::WhoAmI => write!(fields, "{:?}", WhoAmI::from_bits(value)),
+        Reg::CtrlReg1 => write!(fields, "{:?}", CtrlReg1::from_bits(value)),
+        Reg::CtrlReg2 => write!(fields, "{:?}", CtrlReg2::from_bits(value)),
+        Reg::CtrlReg3 => write!(fields, "{:?}", CtrlReg3::from_bits(value)),
+        Reg::FifoCtrl => write!(fields, "{:?}", FifoCtrl::from_bits(value)),
+        Reg::FifoWtm => write!(fields, "{:?}", FifoWtm::from_bits(value)),
+        Reg::I3cIfCtrl => write!(fields, "{:?}", I3cIfCtrl::from_bits(value)),
+        Reg::IntSource => write!(fields, "{:?}", IntSource::from_bits(value)),
+        Reg::FifoStatus1 => write!(fields, "{:?}", FifoStatus1::from_bits(value)),
+        Reg::FifoStatus2 => write!(fields, "{:?}", FifoStatus2::from_bits(value)),
+        Reg::Status => write!(fields, "{:?}", Status::from_bits(value)),
+        _ => Ok(()),
+    }
+}
+
+/// Where [`Fields`] is within the `Debug` output of a bitfield.
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    /// Before the opening brace, in the type name.
+    Header,
+    /// In a field name.
+    Name,
+    /// In the value of a field.
+    Value,
+    /// After the closing brace.
+    Done,
+}
+
+/// Writer parsing the `Debug` output of a bitfield, `Name { a: 1, b: 2 }`, field by field.
+struct Fields<'a> {
+    visit: &'a mut dyn FnMut(&str, u32) -> fmt::Result,
+    state: State,
+    name: [u8; NAME_LEN],
+    name_len: usize,
+    value: u32,
+}
+
+impl Fields<'_> {
+    fn end_field(&mut self) -> fmt::Result {
+        let name = self
+            .name
+            .get(..self.name_len)
+            .and_then(|name| core::str::from_utf8(name).ok())
+            .unwrap_or_default();
+        if !name.starts_with(RESERVED) {
+            (self.visit)(name, self.value)?;
+        }
+        self.name_len = 0;
+        self.value = 0;
+        Ok(())
+    }
+}
+
+impl Write for Fields<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match (self.state, c) {
+                (State::Header, '{') => self.state = State::Name,
+                (State::Name, ':') => self.state = State::Value,
+                (State::Name, ' ') => {}
+                (State::Name, c) => {
+                    if let Some(slot) = self.name.get_mut(self.name_len) {
+                        *slot = c as u8;
+                        self.name_len += 1;
+                    }
+                }
+                (State::Value, ',') => {
+                    self.end_field()?;
+                    self.state = State::Name;
+                }
+                (State::Value, '}') => {
+                    self.end_field()?;
+                    self.state = State::Done;
+                }
+                (State::Value, c) => {
+                    if let Some(digit) = c.to_digit(10) {
+                        self.value = self.value * 10 + digit;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fields of a register value, kept to be compared with those of another value.
+struct FieldList {
+    fields: [([u8; NAME_LEN], usize, u32); 8],
+    len: usize,
+}
+
+impl FieldList {
+    fn new() -> Self {
+        Self {
+            fields: [([0; NAME_LEN], 0, 0); 8],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, name: &str, value: u32) {
+        let Some((buf, len, slot)) = self.fields.get_mut(self.len) else {
+            return;
+        };
+        let name = name.as_bytes();
+        let Some(dst) = buf.get_mut(..name.len()) else {
+            return;
+        };
+        dst.copy_from_slice(name);
+        *len = name.len();
+        *slot = value;
+        self.len += 1;
+    }
+
+    fn get(&self, name: &str) -> Option<u32> {
+        self.fields
+            .iter()
+            .take(self.len)
+            .find(|(buf, len, _)| buf.get(..*len) == Some(name.as_bytes()))
+            .map(|(_, _, value)| *value)
+    }
+}
@@ -0,0 +1,136 @@
+//! Breathing-rate estimation.
+//!
+//! In a closed volume such as a mask or a CPAP tube, each breath produces a slow pressure
+//! oscillation. [`RespirationEstimator`] band-pass filters the converted pressure samples and
+//! counts the oscillations to report the breathing rate in breaths per minute.
+
+use core::f32::consts::TAU;
+
+/// Configuration of a [`RespirationEstimator`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RespirationConfig {
+    /// The sample rate, in Hz.
+    pub rate_hz: f32,
+    /// The lower cut-off of the band-pass filter, in Hz; slower pressure changes are removed.
+    pub low_cut_hz: f32,
+    /// The upper cut-off of the band-pass filter, in Hz; faster pressure changes are removed.
+    pub high_cut_hz: f32,
+    /// The half-width of the band around zero a breath must cross, in hPa.
+    pub hysteresis_hpa: f32,
+}
+
+impl Default for RespirationConfig {
+    /// 25 Hz sampling, 0.1 Hz to 1 Hz band (6 to 60 breaths per minute).
+    fn default() -> Self {
+        Self {
+            rate_hz: 25.0,
+            low_cut_hz: 0.1,
+            high_cut_hz: 1.0,
+            hysteresis_hpa: 0.005,
+        }
+    }
+}
+
+/// Breathing-rate estimator.
+///
+/// The `RespirationEstimator` detects a breath at every rising crossing of the band-passed
+/// pressure and smooths the time between breaths. The rate is dropped when no breath is detected
+/// for twice the period of the lower cut-off, e.g. during an apnea.
+///
+/// ```ignore
+/// let mut breath = RespirationEstimator::new(RespirationConfig::default());
+/// if let Some(bpm) = breath.update(sensor.data_get().await?.pressure.hpa) { /* ... */ }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RespirationEstimator {
+    config: RespirationConfig,
+    hp_coeff: f32,
+    lp_coeff: f32,
+    input: Option<f32>,
+    high_pass: f32,
+    low_pass: f32,
+    positive: Option<bool>,
+    since_breath: Option<u32>,
+    period_samples: Option<f32>,
+}
+
+impl RespirationEstimator {
+    /// Creates an estimator.
+    pub fn new(config: RespirationConfig) -> Self {
+        let dt_s = 1.0 / config.rate_hz;
+        let hp_rc = 1.0 / (TAU * config.low_cut_hz);
+        let lp_rc = 1.0 / (TAU * config.high_cut_hz);
+        Self {
+            config,
+            hp_coeff: hp_rc / (hp_rc + dt_s),
+            lp_coeff: dt_s / (lp_rc + dt_s),
+            input: None,
+            high_pass: 0.0,
+            low_pass: 0.0,
+            positive: None,
+            since_breath: None,
+            period_samples: None,
+        }
+    }
+
+    /// Returns the configuration.
+    pub const fn config(&self) -> RespirationConfig {
+        self.config
+    }
+
+    /// Clears the filters and the estimated rate.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.config);
+    }
+
+    /// Returns the breathing rate, in breaths per minute, or `None` until two breaths are
+    /// detected.
+    pub fn bpm(&self) -> Option<f32> {
+        self.period_samples
+            .map(|period| 60.0 * self.config.rate_hz / period)
+    }
+
+    /// Feeds a pressure sample in hPa and returns the breathing rate, see [`Self::bpm`].
+    pub fn update(&mut self, hpa: f32) -> Option<f32> {
+        // First-order high-pass, then low-pass
+        let previous = self.input.replace(hpa).unwrap_or(hpa);
+        self.high_pass = self.hp_coeff * (self.high_pass + hpa - previous);
+        self.low_pass += self.lp_coeff * (self.high_pass - self.low_pass);
+
+        let band = self.low_pass;
+        let hysteresis = self.config.hysteresis_hpa;
+        let positive = if band > hysteresis {
+            Some(true)
+        } else if band < -hysteresis {
+            Some(false)
+        } else {
+            self.positive
+        };
+        let breath = self.positive == Some(false) && positive == Some(true);
+        self.positive = positive;
+
+        let since_breath = self.since_breath.map(|since| since.saturating_add(1));
+        if breath {
+            if let Some(interval) = since_breath {
+                let interval = interval as f32;
+                self.period_samples = Some(match self.period_samples {
+                    Some(period) => period + 0.25 * (interval - period),
+                    None => interval,
+                });
+            }
+            self.since_breath = Some(0);
+        } else {
+            self.since_breath = since_breath;
+        }
+
+        let timeout = 2.0 * self.config.rate_hz / self.config.low_cut_hz;
+        if self
+            .since_breath
+            .is_some_and(|since| since as f32 > timeout)
+        {
+            self.since_breath = None;
+            self.period_samples = None;
+        }
+        self.bpm()
+    }
+}
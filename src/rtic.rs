@@ -0,0 +1,63 @@
+//! Sampling helpers timestamped with an RTIC monotonic.
+//!
+//! Control loops integrating or differentiating the pressure need the acquisition time of every
+//! sample on the time base of the application. These methods pair each sample with the
+//! `Instant` of an `rtic_time::Monotonic`, read at acquisition time.
+
+use super::{BusOperation, Error, Ilps22qs, bisync, prelude::*};
+
+use rtic_time::Monotonic;
+
+#[bisync]
+impl<B: BusOperation> Ilps22qs<B, OnState> {
+    /// Retrieves sensor data together with the monotonic instant of its acquisition.
+    ///
+    /// The instant is read from `M` right before the output registers, so it is not delayed by
+    /// the bus transfer nor by the conversion of [`Self::data_get`]. When called on data-ready,
+    /// it lags the end of the conversion by the polling latency only.
+    ///
+    /// ```ignore
+    /// let (instant, data) = sensor.data_get_stamped::<Mono>()?;
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(M::Instant, Data), Error<B::Error>>`
+    ///     * `(M::Instant, Data)`: The acquisition instant and the processed sensor data.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    pub async fn data_get_stamped<M: Monotonic>(
+        &mut self,
+    ) -> Result<(M::Instant, Data), Error<B::Error>> {
+        let instant = M::now();
+        let data = self.data_get().await?;
+        Ok((instant, data))
+    }
+
+    /// Retrieves the raw output data together with the monotonic instant of its acquisition.
+    ///
+    /// This function behaves as [`Self::data_get_stamped`], with the single-transaction read of
+    /// [`Self::data_get_fast`] for the highest output data rates.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(M::Instant, RawData), Error<B::Error>>`
+    ///     * `(M::Instant, RawData)`: The acquisition instant and the raw pressure and temperature.
+    ///     * `Err`: Returns an `Error::Bus(B)` if the operation fails due to a bus communication error.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Bus(B)`: Occurs if there is a communication issue with the device, which can prevent
+    ///   successful reading of the sensor data.
+    pub async fn data_get_fast_stamped<M: Monotonic>(
+        &mut self,
+    ) -> Result<(M::Instant, RawData), Error<B::Error>> {
+        let instant = M::now();
+        let data = self.data_get_fast().await?;
+        Ok((instant, data))
+    }
+}
@@ -0,0 +1,87 @@
+//! Device-independent sensor traits.
+//!
+//! [`PressureSensor`] and [`TemperatureSensor`] cover the operations most application code
+//! needs: reading converted values, checking for new data and configuring the output data rate.
+//! Writing the application against these traits instead of [`Ilps22qs`] allows unit testing it
+//! with a mock, without any bus.
+//!
+//! ```ignore
+//! async fn altitude_hold<S: PressureSensor>(sensor: &mut S) -> Result<(), S::Error> {
+//!     sensor.rate_set(Odr::_50hz).await?;
+//!     if sensor.pressure_ready().await? {
+//!         let hpa = sensor.pressure_hpa().await?;
+//!         // ...
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use super::{
+    BusOperation, Error, Ilps22qs, PROPERTY_ENABLE, RegisterOperation, bisync, prelude::*,
+};
+
+/// A sensor measuring pressure.
+#[bisync]
+#[allow(async_fn_in_trait)]
+pub trait PressureSensor {
+    /// Error reported by the sensor.
+    type Error;
+
+    /// Reads the latest pressure, in hPa.
+    async fn pressure_hpa(&mut self) -> Result<f32, Self::Error>;
+
+    /// Returns `true` if a new pressure sample is available.
+    async fn pressure_ready(&mut self) -> Result<bool, Self::Error>;
+
+    /// Configures the output data rate.
+    async fn rate_set(&mut self, odr: Odr) -> Result<(), Self::Error>;
+}
+
+/// A sensor measuring temperature.
+#[bisync]
+#[allow(async_fn_in_trait)]
+pub trait TemperatureSensor {
+    /// Error reported by the sensor.
+    type Error;
+
+    /// Reads the latest temperature, in degrees Celsius (°C).
+    async fn temperature_deg_c(&mut self) -> Result<f32, Self::Error>;
+
+    /// Returns `true` if a new temperature sample is available.
+    async fn temperature_ready(&mut self) -> Result<bool, Self::Error>;
+}
+
+#[bisync]
+impl<B: BusOperation> PressureSensor for Ilps22qs<B, OnState> {
+    type Error = Error<B::Error>;
+
+    /// Reads the latest pressure using the full scale kept by the driver, see
+    /// [`Ilps22qs::data_get`].
+    async fn pressure_hpa(&mut self) -> Result<f32, Self::Error> {
+        Ok(self.data_get().await?.pressure.hpa)
+    }
+
+    async fn pressure_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(Status::read(self).await?.p_da() == PROPERTY_ENABLE)
+    }
+
+    /// Updates the output data rate, keeping the other conversion parameters applied by the
+    /// driver.
+    async fn rate_set(&mut self, odr: Odr) -> Result<(), Self::Error> {
+        let md = self.active_mode();
+        self.mode_set(&Md { odr, ..md }).await
+    }
+}
+
+#[bisync]
+impl<B: BusOperation> TemperatureSensor for Ilps22qs<B, OnState> {
+    type Error = Error<B::Error>;
+
+    async fn temperature_deg_c(&mut self) -> Result<f32, Self::Error> {
+        Ok(self.temp_data_get().await?.deg_c)
+    }
+
+    async fn temperature_ready(&mut self) -> Result<bool, Self::Error> {
+        self.temp_data_ready().await
+    }
+}
@@ -0,0 +1,92 @@
+//! Interior-mutability wrapper sharing the blocking driver between execution contexts.
+
+use super::{BusOperation, Error, Ilps22qs, prelude::*};
+
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// Blocking driver shared through a `critical-section` mutex.
+///
+/// `SharedIlps22qs` can be stored in a static cell and used through `&self` from the idle loop,
+/// timer callbacks or interrupt handlers: every call runs the bus transaction inside a critical
+/// section, so transfers from different contexts never interleave. Operations not mirrored here
+/// are available through [`SharedIlps22qs::with`]; calling the same `SharedIlps22qs` again from
+/// within it returns `Error::Busy` instead of panicking.
+///
+/// # Example
+///
+/// ```ignore
+/// let sensor = SharedIlps22qs::new(Ilps22qs::new_i2c(i2c, I2CAddress::SD0High));
+///
+/// let data = sensor.data_get()?;
+/// ```
+pub struct SharedIlps22qs<B: BusOperation> {
+    sensor: Mutex<RefCell<Ilps22qs<B, OnState>>>,
+}
+
+impl<B: BusOperation> SharedIlps22qs<B> {
+    /// Wraps a driver instance.
+    pub const fn new(sensor: Ilps22qs<B, OnState>) -> Self {
+        Self {
+            sensor: Mutex::new(RefCell::new(sensor)),
+        }
+    }
+
+    /// Returns the wrapped driver.
+    pub fn into_inner(self) -> Ilps22qs<B, OnState> {
+        self.sensor.into_inner().into_inner()
+    }
+
+    /// Runs `f` with exclusive access to the driver, inside a critical section.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Busy`: Returned, without running `f`, when called from within `f` on the same
+    ///   `SharedIlps22qs`, as the driver is already borrowed.
+    pub fn with<R>(
+        &self,
+        f: impl FnOnce(&mut Ilps22qs<B, OnState>) -> R,
+    ) -> Result<R, Error<B::Error>> {
+        critical_section::with(|cs| {
+            let mut sensor = self
+                .sensor
+                .borrow(cs)
+                .try_borrow_mut()
+                .map_err(|_| Error::Busy)?;
+            Ok(f(&mut sensor))
+        })
+    }
+
+    /// Reads the device status, see [`Ilps22qs::status_get`].
+    pub fn status_get(&self) -> Result<Stat, Error<B::Error>> {
+        self.with(|sensor| sensor.status_get())?
+    }
+
+    /// Reads the interrupt sources, see [`Ilps22qs::all_sources_get`].
+    pub fn all_sources_get(&self) -> Result<AllSources, Error<B::Error>> {
+        self.with(|sensor| sensor.all_sources_get())?
+    }
+
+    /// Reads the converted output data, see [`Ilps22qs::data_get`].
+    #[cfg(feature = "float")]
+    pub fn data_get(&self) -> Result<Data, Error<B::Error>> {
+        self.with(|sensor| sensor.data_get())?
+    }
+
+    /// Reads the AH/QVAR data, see [`Ilps22qs::ah_qvar_data_get`].
+    #[cfg(feature = "qvar")]
+    pub fn ah_qvar_data_get(&self) -> Result<AhQvarData, Error<B::Error>> {
+        self.with(|sensor| sensor.ah_qvar_data_get())?
+    }
+
+    /// Reads the FIFO fill level, see [`Ilps22qs::fifo_level_get`].
+    pub fn fifo_level_get(&self) -> Result<u8, Error<B::Error>> {
+        self.with(|sensor| sensor.fifo_level_get())?
+    }
+
+    /// Reads samples out of the FIFO, see [`Ilps22qs::fifo_data_get`].
+    #[cfg(feature = "float")]
+    pub fn fifo_data_get(&self, samp: u8, data: &mut [FifoData]) -> Result<usize, Error<B::Error>> {
+        self.with(|sensor| sensor.fifo_data_get(samp, data))?
+    }
+}
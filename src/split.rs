@@ -0,0 +1,240 @@
+//! Configuration and data handles sharing a single driver instance.
+//!
+//! [`Ilps22qs::split`] hands out a [`Config`] handle, meant for the task owning the sensor
+//! setup, and a [`Reader`] handle, meant for the task polling samples. Both handles borrow the
+//! same `RefCell`, so every bus transaction is serialized: if one handle is in the middle of an
+//! operation, the other one fails with `Error::Busy` instead of corrupting the transfer.
+
+use super::{BusOperation, DelayNs, Error, Ilps22qs, bisync, prelude::*};
+
+#[cfg(feature = "float")]
+use crate::filter::SpikeFilter;
+use core::cell::{RefCell, RefMut};
+
+/// Handle owning the reconfiguration of a split driver.
+pub struct Config<'a, B: BusOperation> {
+    sensor: &'a RefCell<Ilps22qs<B, OnState>>,
+}
+
+/// Handle owning the data reads of a split driver.
+pub struct Reader<'a, B: BusOperation> {
+    sensor: &'a RefCell<Ilps22qs<B, OnState>>,
+}
+
+#[bisync]
+impl<B: BusOperation> Ilps22qs<B, OnState> {
+    /// Splits the driver into a configuration handle and a data handle.
+    ///
+    /// The driver is kept in `sensor`, which must outlive both handles (e.g. a `static` cell in
+    /// RTOS designs).
+    ///
+    /// # Parameters
+    ///
+    /// * `sensor`: The `RefCell` holding the driver.
+    ///
+    /// # Returns
+    ///
+    /// * `(Config, Reader)`: The configuration and data handles.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let sensor = RefCell::new(Ilps22qs::new_i2c(i2c, I2CAddress::SD0High));
+    /// let (mut config, mut reader) = Ilps22qs::split(&sensor);
+    /// config.mode_set(&md)?;
+    /// let data = reader.data_get()?;
+    /// ```
+    pub fn split(sensor: &RefCell<Self>) -> (Config<'_, B>, Reader<'_, B>) {
+        (Config { sensor }, Reader { sensor })
+    }
+}
+
+fn borrow<B: BusOperation>(
+    sensor: &RefCell<Ilps22qs<B, OnState>>,
+) -> Result<RefMut<'_, Ilps22qs<B, OnState>>, Error<B::Error>> {
+    sensor.try_borrow_mut().map_err(|_| Error::Busy)
+}
+
+// The borrow is held across the bus transaction on purpose: contention between the two handles
+// is reported as `Error::Busy` by `try_borrow_mut`, it never panics.
+#[bisync]
+#[allow(clippy::await_holding_refcell_ref)]
+impl<B: BusOperation> Config<'_, B> {
+    /// Initializes the device, see [`Ilps22qs::init_set`].
+    pub async fn init_set<D: DelayNs>(
+        &mut self,
+        val: Init,
+        delay: &mut D,
+    ) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.init_set(val, delay).await
+    }
+
+    /// Configures the bus operating mode, see [`Ilps22qs::bus_mode_set`].
+    pub async fn bus_mode_set(&mut self, val: BusMode) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.bus_mode_set(val).await
+    }
+
+    /// Configures the hardware pins, see [`Ilps22qs::pin_conf_set`].
+    pub async fn pin_conf_set(&mut self, val: &PinConf) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.pin_conf_set(val).await
+    }
+
+    /// Sets the conversion parameters, see [`Ilps22qs::mode_set`].
+    pub async fn mode_set(&mut self, val: &Md) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.mode_set(val).await
+    }
+
+    /// Reads back the conversion parameters, see [`Ilps22qs::mode_get`].
+    pub async fn mode_get(&mut self) -> Result<Md, Error<B::Error>> {
+        borrow(self.sensor)?.mode_get().await
+    }
+
+    /// Adopts the conversion parameters of the device, see [`Ilps22qs::mode_adopt`].
+    pub async fn mode_adopt(&mut self) -> Result<Md, Error<B::Error>> {
+        borrow(self.sensor)?.mode_adopt().await
+    }
+
+    /// Starts continuous conversions, see [`Ilps22qs::start_sampling`].
+    pub async fn start_sampling(&mut self, md: &Md) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.start_sampling(md).await
+    }
+
+    /// Stops continuous conversions, see [`Ilps22qs::stop_sampling`].
+    pub async fn stop_sampling(&mut self) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.stop_sampling().await
+    }
+
+    /// Enables or disables the AH/QVAR function, see [`Ilps22qs::ah_qvar_en_set`].
+    #[cfg(feature = "qvar")]
+    pub async fn ah_qvar_en_set(&mut self, val: bool) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.ah_qvar_en_set(val).await
+    }
+
+    /// Configures the FIFO, see [`Ilps22qs::fifo_mode_set`].
+    pub async fn fifo_mode_set(&mut self, val: &FifoMd) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.fifo_mode_set(val).await
+    }
+
+    /// Configures the interrupt signal, see [`Ilps22qs::interrupt_mode_set`].
+    pub async fn interrupt_mode_set(&mut self, int_latched: bool) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.interrupt_mode_set(int_latched).await
+    }
+
+    /// Configures the threshold interrupt, see [`Ilps22qs::int_on_threshold_mode_set`].
+    pub async fn int_on_threshold_mode_set(
+        &mut self,
+        val: &IntThMd,
+    ) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.int_on_threshold_mode_set(val).await
+    }
+
+    /// Configures the reference pressure, see [`Ilps22qs::reference_mode_set`].
+    pub async fn reference_mode_set(&mut self, val: &RefMd) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.reference_mode_set(val).await
+    }
+
+    /// Configures the interrupts, see [`Ilps22qs::int_config_set`].
+    pub async fn int_config_set(&mut self, val: &IntConfig) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.int_config_set(val).await
+    }
+
+    /// Sets the pressure offset, see [`Ilps22qs::opc_set`].
+    pub async fn opc_set(&mut self, val: i16) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.opc_set(val).await
+    }
+
+    /// Calibrates the pressure offset, see [`Ilps22qs::calibrate_offset`].
+    #[cfg(feature = "float")]
+    pub async fn calibrate_offset<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        known_hpa: f32,
+        n_samples: u16,
+    ) -> Result<i16, Error<B::Error>> {
+        borrow(self.sensor)?
+            .calibrate_offset(delay, known_hpa, n_samples)
+            .await
+    }
+
+    /// Zeroes the pressure output, see [`Ilps22qs::tare`].
+    #[cfg(feature = "float")]
+    pub async fn tare(&mut self) -> Result<f32, Error<B::Error>> {
+        borrow(self.sensor)?.tare().await
+    }
+
+    /// Installs the temperature compensation, see [`Ilps22qs::temp_compensation_set`].
+    #[cfg(feature = "float")]
+    pub fn temp_compensation_set(
+        &mut self,
+        val: Option<TempCompensation>,
+    ) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.temp_compensation_set(val);
+        Ok(())
+    }
+
+    /// Installs the spike filter, see [`Ilps22qs::spike_filter_set`].
+    #[cfg(feature = "float")]
+    pub fn spike_filter_set(&mut self, val: Option<SpikeFilter>) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.spike_filter_set(val);
+        Ok(())
+    }
+}
+
+#[bisync]
+#[allow(clippy::await_holding_refcell_ref)]
+impl<B: BusOperation> Reader<'_, B> {
+    /// Triggers a one-shot conversion, see [`Ilps22qs::trigger_sw`].
+    pub async fn trigger_sw(&mut self) -> Result<(), Error<B::Error>> {
+        borrow(self.sensor)?.trigger_sw().await
+    }
+
+    /// Reads the device status, see [`Ilps22qs::status_get`].
+    pub async fn status_get(&mut self) -> Result<Stat, Error<B::Error>> {
+        borrow(self.sensor)?.status_get().await
+    }
+
+    /// Reads the interrupt sources, see [`Ilps22qs::all_sources_get`].
+    pub async fn all_sources_get(&mut self) -> Result<AllSources, Error<B::Error>> {
+        borrow(self.sensor)?.all_sources_get().await
+    }
+
+    /// Reads the converted output data, see [`Ilps22qs::data_get`].
+    #[cfg(feature = "float")]
+    pub async fn data_get(&mut self) -> Result<Data, Error<B::Error>> {
+        borrow(self.sensor)?.data_get().await
+    }
+
+    /// Reads the AH/QVAR data, see [`Ilps22qs::ah_qvar_data_get`].
+    #[cfg(feature = "qvar")]
+    pub async fn ah_qvar_data_get(&mut self) -> Result<AhQvarData, Error<B::Error>> {
+        borrow(self.sensor)?.ah_qvar_data_get().await
+    }
+
+    /// Reads the FIFO fill level, see [`Ilps22qs::fifo_level_get`].
+    pub async fn fifo_level_get(&mut self) -> Result<u8, Error<B::Error>> {
+        borrow(self.sensor)?.fifo_level_get().await
+    }
+
+    /// Reads samples out of the FIFO, see [`Ilps22qs::fifo_data_get`].
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get(
+        &mut self,
+        samp: u8,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        borrow(self.sensor)?.fifo_data_get(samp, data).await
+    }
+
+    /// Reads decimated samples out of the FIFO, see [`Ilps22qs::fifo_data_get_decimated`].
+    #[cfg(feature = "float")]
+    pub async fn fifo_data_get_decimated(
+        &mut self,
+        samp: u8,
+        decimation: u8,
+        data: &mut [FifoData],
+    ) -> Result<usize, Error<B::Error>> {
+        borrow(self.sensor)?
+            .fifo_data_get_decimated(samp, decimation, data)
+            .await
+    }
+}
@@ -0,0 +1,105 @@
+//! Windowed statistics over converted sensor samples.
+//!
+//! [`Stats`] keeps the last `N` samples and reports their minimum, maximum, mean and variance,
+//! e.g. for stability checks or leak tests fed from `data_get` or from FIFO drains.
+
+/// Statistics accumulator over a sliding window of `N` samples.
+///
+/// ```ignore
+/// let mut stats = Stats::<32>::new();
+/// stats.extend(fifo.iter().map(|sample| sample.hpa));
+/// let stable = stats.is_full() && stats.variance().is_some_and(|var| var < 0.0004);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Stats<const N: usize> {
+    window: [f32; N],
+    next: usize,
+    len: usize,
+}
+
+impl<const N: usize> Stats<N> {
+    /// Creates an empty accumulator.
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "statistics window must not be empty") };
+        Self {
+            window: [0.0; N],
+            next: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds a sample, discarding the oldest one if the window is full.
+    pub fn push(&mut self, sample: f32) {
+        if let Some(slot) = self.window.get_mut(self.next) {
+            *slot = sample;
+        }
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Adds every sample of `samples`, in order.
+    pub fn extend(&mut self, samples: impl IntoIterator<Item = f32>) {
+        for sample in samples {
+            self.push(sample);
+        }
+    }
+
+    /// Clears the window.
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns the number of samples in the window.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no sample was added since the creation or the last reset.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` once the window holds `N` samples.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the smallest sample of the window.
+    pub fn min(&self) -> Option<f32> {
+        self.samples().reduce(f32::min)
+    }
+
+    /// Returns the largest sample of the window.
+    pub fn max(&self) -> Option<f32> {
+        self.samples().reduce(f32::max)
+    }
+
+    /// Returns the mean of the window.
+    pub fn mean(&self) -> Option<f32> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.samples().sum::<f32>() / self.len as f32)
+    }
+
+    /// Returns the population variance of the window.
+    pub fn variance(&self) -> Option<f32> {
+        let mean = self.mean()?;
+        let sum = self
+            .samples()
+            .map(|sample| (sample - mean) * (sample - mean))
+            .sum::<f32>();
+        Some(sum / self.len as f32)
+    }
+
+    fn samples(&self) -> impl Iterator<Item = f32> + '_ {
+        self.window.iter().take(self.len).copied()
+    }
+}
+
+impl<const N: usize> Default for Stats<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
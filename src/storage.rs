@@ -0,0 +1,122 @@
+//! NOR flash logging backend over `embedded-storage`.
+
+use crate::logger::LogBackend;
+
+use embedded_storage::nor_flash::NorFlash;
+
+/// Size of the staging buffer used to batch flash writes.
+const CHUNK_SIZE: usize = 64;
+
+/// Errors reported by [`NorFlashBackend`].
+#[derive(Debug)]
+pub enum StorageError<E> {
+    /// The flash driver reported an error.
+    Flash(E),
+    /// The flash region has no room left for the samples.
+    Full,
+}
+
+/// Logging backend appending samples to a region of a NOR flash.
+///
+/// Samples are stored as consecutive little-endian `f32` values, starting at the beginning of
+/// the region. Each [`LogBackend::store`] call is padded to the flash write size with erased
+/// bytes, which read back as NaN words and can be skipped when decoding. The region must be
+/// erased, e.g. with [`NorFlashBackend::erase`], before the first use.
+///
+/// ```ignore
+/// let mut backend = NorFlashBackend::new(flash, 0x1_0000, 0x2_0000);
+/// backend.erase()?;
+/// logger.dump_to(&mut backend)?;
+/// ```
+pub struct NorFlashBackend<F> {
+    flash: F,
+    start: u32,
+    end: u32,
+    cursor: u32,
+}
+
+impl<F: NorFlash> NorFlashBackend<F> {
+    /// Creates a backend writing into the `start..end` region of `flash`.
+    ///
+    /// Both bounds must be aligned to `F::ERASE_SIZE`.
+    pub fn new(flash: F, start: u32, end: u32) -> Self {
+        Self {
+            flash,
+            start,
+            end,
+            cursor: start,
+        }
+    }
+
+    /// Erases the whole region and rewinds the backend to its beginning.
+    ///
+    /// # Errors
+    ///
+    /// * `StorageError::Flash(E)`: Returned if the flash driver fails.
+    pub fn erase(&mut self) -> Result<(), StorageError<F::Error>> {
+        self.flash
+            .erase(self.start, self.end)
+            .map_err(StorageError::Flash)?;
+        self.cursor = self.start;
+        Ok(())
+    }
+
+    /// Returns the number of bytes written since the beginning of the region.
+    pub fn position(&self) -> u32 {
+        self.cursor - self.start
+    }
+
+    /// Returns the flash driver.
+    pub fn into_inner(self) -> F {
+        self.flash
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> Result<(), StorageError<F::Error>> {
+        let len = bytes.len() as u32;
+        if self.end - self.cursor < len {
+            return Err(StorageError::Full);
+        }
+        self.flash
+            .write(self.cursor, bytes)
+            .map_err(StorageError::Flash)?;
+        self.cursor += len;
+        Ok(())
+    }
+}
+
+impl<F: NorFlash> LogBackend for NorFlashBackend<F> {
+    type Error = StorageError<F::Error>;
+
+    fn store(&mut self, samples: impl Iterator<Item = f32>) -> Result<(), Self::Error> {
+        const {
+            assert!(
+                CHUNK_SIZE.is_multiple_of(F::WRITE_SIZE),
+                "flash write size not supported"
+            )
+        };
+
+        let mut chunk = [0xFF; CHUNK_SIZE];
+        let mut len = 0;
+        for sample in samples {
+            if let Some(slot) = chunk.get_mut(len..len + 4) {
+                slot.copy_from_slice(&sample.to_le_bytes());
+            }
+            len += 4;
+            if len == CHUNK_SIZE {
+                self.write(&chunk)?;
+                len = 0;
+            }
+        }
+
+        if len > 0 {
+            let padded = len.next_multiple_of(F::WRITE_SIZE);
+            if let Some(padding) = chunk.get_mut(len..padded) {
+                padding.fill(0xFF);
+            }
+            if let Some(bytes) = chunk.get(..padded) {
+                self.write(bytes)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+//! Compact binary telemetry frames.
+//!
+//! Samples are wrapped in a [`Frame`] carrying a sequence number and a typed [`Payload`], then
+//! serialized with postcard and COBS-framed, so a host tool reading a byte stream can split the
+//! frames on the `0x00` delimiter, detect dropped frames and decode each sample unambiguously.
+//!
+//! ```ignore
+//! let mut encoder = Encoder::new();
+//! let mut buf = [0u8; MAX_FRAME_SIZE];
+//! let frame = encoder.encode(&data, &mut buf)?;
+//! uart.write_all(frame)?;
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Size of a buffer large enough for any encoded frame, delimiter included.
+pub const MAX_FRAME_SIZE: usize = 32;
+
+/// A telemetry frame.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct Frame {
+    /// Sequence number, incremented by the [`Encoder`] for every frame and wrapping around.
+    pub seq: u16,
+    /// The sample carried by the frame.
+    pub payload: Payload,
+}
+
+/// The sample carried by a [`Frame`], tagged with its type.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Payload {
+    /// A sample read with `data_get`.
+    Data {
+        /// The raw pressure output.
+        pressure_raw: i32,
+        /// The converted pressure, in hPa.
+        pressure_hpa: f32,
+        /// The raw temperature output.
+        temperature_raw: i16,
+        /// The converted temperature, in °C.
+        temperature_deg_c: f32,
+        /// The AH/QVAR output of an interleaved sample.
+        ah_qvar_lsb: i32,
+    },
+    /// A sample read with `fifo_data_get`.
+    Fifo {
+        /// The raw FIFO output.
+        raw: i32,
+        /// The converted pressure, in hPa.
+        hpa: f32,
+        /// The AH/QVAR output of an interleaved sample.
+        lsb: i32,
+        /// The reconstructed acquisition time, in microseconds.
+        timestamp_us: u64,
+    },
+    /// A sample read with `ah_qvar_data_get`.
+    AhQvar {
+        /// The raw AH/QVAR output.
+        raw: i32,
+        /// The converted AH/QVAR value, in mV.
+        mv: f32,
+    },
+}
+
+/// Encoder assigning sequence numbers to outgoing frames.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Encoder {
+    seq: u16,
+}
+
+impl Encoder {
+    /// Creates an encoder starting at sequence number `0`.
+    pub const fn new() -> Self {
+        Self { seq: 0 }
+    }
+
+    /// Encodes `payload` in a COBS-framed postcard frame.
+    ///
+    /// # Parameters
+    ///
+    /// * `payload`: The sample to encode.
+    /// * `buf`: The output buffer, see [`MAX_FRAME_SIZE`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<&mut [u8], postcard::Error>`
+    ///     * `Ok`: The part of `buf` holding the frame, terminated by `0x00`.
+    ///     * `Err`: Returned if `buf` is too small.
+    pub fn encode<'a>(
+        &mut self,
+        payload: impl Into<Payload>,
+        buf: &'a mut [u8],
+    ) -> Result<&'a mut [u8], postcard::Error> {
+        let frame = Frame {
+            seq: self.seq,
+            payload: payload.into(),
+        };
+        let encoded = postcard::to_slice_cobs(&frame, buf)?;
+        self.seq = self.seq.wrapping_add(1);
+        Ok(encoded)
+    }
+}
+
+impl Frame {
+    /// Decodes a COBS-framed postcard frame, in place.
+    ///
+    /// # Errors
+    ///
+    /// * `postcard::Error`: Returned if `buf` does not hold a valid frame.
+    pub fn decode(buf: &mut [u8]) -> Result<Self, postcard::Error> {
+        postcard::from_bytes_cobs(buf)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<&crate::asynchronous::prelude::Data> for Payload {
+    fn from(data: &crate::asynchronous::prelude::Data) -> Self {
+        Payload::Data {
+            pressure_raw: data.pressure.raw,
+            pressure_hpa: data.pressure.hpa,
+            temperature_raw: data.heat.raw,
+            temperature_deg_c: data.heat.deg_c,
+            ah_qvar_lsb: data.ah_qvar.lsb,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<&crate::asynchronous::prelude::FifoData> for Payload {
+    fn from(data: &crate::asynchronous::prelude::FifoData) -> Self {
+        Payload::Fifo {
+            raw: data.raw,
+            hpa: data.hpa,
+            lsb: data.lsb,
+            timestamp_us: data.timestamp_us,
+        }
+    }
+}
+
+#[cfg(all(feature = "async", feature = "qvar"))]
+impl From<&crate::asynchronous::prelude::AhQvarData> for Payload {
+    fn from(data: &crate::asynchronous::prelude::AhQvarData) -> Self {
+        Payload::AhQvar {
+            raw: data.raw,
+            mv: data.mv,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl From<&crate::blocking::prelude::Data> for Payload {
+    fn from(data: &crate::blocking::prelude::Data) -> Self {
+        Payload::Data {
+            pressure_raw: data.pressure.raw,
+            pressure_hpa: data.pressure.hpa,
+            temperature_raw: data.heat.raw,
+            temperature_deg_c: data.heat.deg_c,
+            ah_qvar_lsb: data.ah_qvar.lsb,
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl From<&crate::blocking::prelude::FifoData> for Payload {
+    fn from(data: &crate::blocking::prelude::FifoData) -> Self {
+        Payload::Fifo {
+            raw: data.raw,
+            hpa: data.hpa,
+            lsb: data.lsb,
+            timestamp_us: data.timestamp_us,
+        }
+    }
+}
+
+#[cfg(all(feature = "blocking", feature = "qvar"))]
+impl From<&crate::blocking::prelude::AhQvarData> for Payload {
+    fn from(data: &crate::blocking::prelude::AhQvarData) -> Self {
+        Payload::AhQvar {
+            raw: data.raw,
+            mv: data.mv,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Pressure tendency analysis.
+//!
+//! [`Trend`] keeps the last `N` pressure samples taken at a fixed interval and classifies the
+//! pressure as rising, steady or falling from the least-squares slope over the window. With one
+//! sample every 10 minutes, `Trend::<18>` covers the 3 h window used for the barometric
+//! tendency of weather reports.
+//!
+//! [`StormDetector`] builds on the same analysis to flag rapid pressure drops.
+
+/// Represents the pressure tendency over the analysis window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tendency {
+    /// The pressure rises faster than the steady band.
+    Rising,
+    /// The pressure change stays within the steady band.
+    Steady,
+    /// The pressure falls faster than the steady band.
+    Falling,
+}
+
+/// Represents the result of a tendency analysis.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TrendReport {
+    /// The classification of the rate.
+    pub tendency: Tendency,
+    /// The pressure rate of change, in hPa per hour.
+    pub rate_hpa_per_h: f32,
+}
+
+/// Pressure tendency analyzer over a sliding window of `N` samples.
+///
+/// ```ignore
+/// // One sample every 10 minutes, steady within ±0.5 hPa/h
+/// let mut trend = Trend::<18>::new(600.0, 0.5);
+/// trend.push(data.pressure.hpa);
+/// if let Some(report) = trend.report() { /* ... */ }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Trend<const N: usize> {
+    window: [f32; N],
+    next: usize,
+    len: usize,
+    interval_s: f32,
+    steady_hpa_per_h: f32,
+}
+
+impl<const N: usize> Trend<N> {
+    /// Creates an empty analyzer.
+    ///
+    /// # Parameters
+    ///
+    /// * `interval_s`: The time between two pushed samples, in seconds.
+    /// * `steady_hpa_per_h`: The largest rate magnitude classified as [`Tendency::Steady`].
+    pub const fn new(interval_s: f32, steady_hpa_per_h: f32) -> Self {
+        const { assert!(N > 1, "trend window must hold at least two samples") };
+        Self {
+            window: [0.0; N],
+            next: 0,
+            len: 0,
+            interval_s,
+            steady_hpa_per_h,
+        }
+    }
+
+    /// Adds a pressure sample in hPa, discarding the oldest one if the window is full.
+    pub fn push(&mut self, hpa: f32) {
+        if let Some(slot) = self.window.get_mut(self.next) {
+            *slot = hpa;
+        }
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Clears the window.
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns the number of samples in the window.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no sample was added since the creation or the last reset.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` once the window holds `N` samples.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the time span covered by a full window, in seconds.
+    pub fn window_s(&self) -> f32 {
+        (N - 1) as f32 * self.interval_s
+    }
+
+    /// Returns the least-squares pressure slope over the window, in hPa per hour.
+    ///
+    /// At least two samples are needed.
+    pub fn rate_hpa_per_h(&self) -> Option<f32> {
+        if self.len < 2 {
+            return None;
+        }
+        let n = self.len as f32;
+        let mean_t = (n - 1.0) / 2.0;
+        let mean_p = self.samples().sum::<f32>() / n;
+        let (cov, var) = self
+            .samples()
+            .enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, hpa)| {
+                let dt = i as f32 - mean_t;
+                (cov + dt * (hpa - mean_p), var + dt * dt)
+            });
+        Some(cov / var * 3600.0 / self.interval_s)
+    }
+
+    /// Classifies the pressure tendency over the window.
+    ///
+    /// At least two samples are needed.
+    pub fn report(&self) -> Option<TrendReport> {
+        let rate_hpa_per_h = self.rate_hpa_per_h()?;
+        let tendency = if rate_hpa_per_h > self.steady_hpa_per_h {
+            Tendency::Rising
+        } else if rate_hpa_per_h < -self.steady_hpa_per_h {
+            Tendency::Falling
+        } else {
+            Tendency::Steady
+        };
+        Some(TrendReport {
+            tendency,
+            rate_hpa_per_h,
+        })
+    }
+
+    fn samples(&self) -> impl Iterator<Item = f32> + '_ {
+        let oldest = (self.next + N - self.len) % N;
+        self.window
+            .iter()
+            .cycle()
+            .skip(oldest)
+            .take(self.len)
+            .copied()
+    }
+}
+
+/// Rapid pressure-drop detector.
+///
+/// The `StormDetector` raises an alert once the pressure falls faster than a configurable rate
+/// over a full window, and clears it as soon as the fall slows down below that rate. A drop of
+/// 1 hPa/h sustained over 3 h is a common storm warning criterion.
+///
+/// ```ignore
+/// // One sample every 10 minutes, alert on drops faster than 1 hPa/h over 3 h
+/// let mut storm = StormDetector::<19>::new(600.0, 1.0);
+/// if storm.push(data.pressure.hpa) { /* raise the warning */ }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StormDetector<const N: usize> {
+    trend: Trend<N>,
+    drop_hpa_per_h: f32,
+    alert: bool,
+}
+
+impl<const N: usize> StormDetector<N> {
+    /// Creates a detector with no alert raised.
+    ///
+    /// # Parameters
+    ///
+    /// * `interval_s`: The time between two pushed samples, in seconds.
+    /// * `drop_hpa_per_h`: The fall rate magnitude raising the alert, in hPa per hour.
+    pub const fn new(interval_s: f32, drop_hpa_per_h: f32) -> Self {
+        Self {
+            trend: Trend::new(interval_s, drop_hpa_per_h),
+            drop_hpa_per_h,
+            alert: false,
+        }
+    }
+
+    /// Adds a pressure sample in hPa and returns `true` while the alert is raised.
+    pub fn push(&mut self, hpa: f32) -> bool {
+        self.trend.push(hpa);
+        self.alert = self.trend.is_full()
+            && self
+                .trend
+                .rate_hpa_per_h()
+                .is_some_and(|rate| rate < -self.drop_hpa_per_h);
+        self.alert
+    }
+
+    /// Returns `true` while the alert is raised.
+    pub const fn is_alert(&self) -> bool {
+        self.alert
+    }
+
+    /// Returns the current pressure rate of change, in hPa per hour.
+    pub fn rate_hpa_per_h(&self) -> Option<f32> {
+        self.trend.rate_hpa_per_h()
+    }
+
+    /// Clears the window and the alert.
+    pub fn reset(&mut self) {
+        self.trend.reset();
+        self.alert = false;
+    }
+}
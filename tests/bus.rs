@@ -0,0 +1,260 @@
+//! Transactions issued by the transports of the `bus` module, recorded over a register file
+//! standing in for the device.
+
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use common::Registers;
+use core::cell::RefCell;
+use core::convert::Infallible;
+use embedded_hal::digital::{self, OutputPin};
+use embedded_hal::spi::{self, SpiBus};
+use ilps22qs_rs::blocking::BusOperation;
+use ilps22qs_rs::blocking::bus::chunked::{Chunked, MAX_WRITE_CHUNK};
+use ilps22qs_rs::blocking::bus::record::{Recorder, TAG_READ, TAG_WRITE, TAG_WRITE_READ};
+use ilps22qs_rs::blocking::bus::soft_cs::{SoftCsError, SoftCsSpi};
+use ilps22qs_rs::blocking::bus::write_then_read::{ReadMode, WriteThenRead};
+
+/// Splits a [`Recorder`] log into `(tag, first byte, payload length)` transactions, the first
+/// byte being the register address of writes and write-reads.
+fn transactions(mut log: &[u8]) -> Vec<(u8, Option<u8>, usize)> {
+    let mut transactions = Vec::new();
+    while let [tag, rest @ ..] = log {
+        let (address, rest) = match (*tag, rest) {
+            (TAG_WRITE_READ, [address, rest @ ..]) => (Some(*address), rest),
+            (_, rest) => (None, rest),
+        };
+        let [lo, hi, rest @ ..] = rest else {
+            panic!("truncated log");
+        };
+        let len = usize::from(u16::from_le_bytes([*lo, *hi]));
+        let address = address.or(if *tag == TAG_WRITE {
+            rest.first().copied()
+        } else {
+            None
+        });
+        transactions.push((*tag, address, len));
+        log = &rest[len..];
+    }
+    transactions
+}
+
+#[test]
+fn chunked_fifo_burst_wraps_at_press_h() {
+    let mut log = [0; 256];
+    let mut bus = Chunked::new(Recorder::new(Registers::new(), &mut log), 2);
+
+    // Starting at FIFO_DATA_OUT_PRESS_L, the second chunk rolls back to PRESS_XL
+    let mut rbuf = [0; 6];
+    bus.write_byte_read_bytes(&[0x79], &mut rbuf).unwrap();
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [
+            (TAG_WRITE_READ, Some(0x79), 2),
+            (TAG_WRITE_READ, Some(0x78), 2),
+            (TAG_WRITE_READ, Some(0x7A), 2),
+        ]
+    );
+
+    // Outside the FIFO window the chunks follow the plain auto-increment
+    bus.bus.clear();
+    let mut rbuf = [0; 5];
+    bus.write_byte_read_bytes(&[0x28], &mut rbuf).unwrap();
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [
+            (TAG_WRITE_READ, Some(0x28), 2),
+            (TAG_WRITE_READ, Some(0x2A), 2),
+            (TAG_WRITE_READ, Some(0x2C), 1),
+        ]
+    );
+}
+
+#[test]
+fn chunked_reads_and_writes_within_the_limit_are_forwarded() {
+    let mut log = [0; 256];
+    let mut bus = Chunked::new(Recorder::new(Registers::new(), &mut log), 4);
+
+    bus.write_byte_read_bytes(&[0x78], &mut [0; 4]).unwrap();
+    bus.write_bytes(&[0x10, 1, 2, 3, 4]).unwrap();
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [(TAG_WRITE_READ, Some(0x78), 4), (TAG_WRITE, Some(0x10), 5)]
+    );
+}
+
+#[test]
+fn chunked_writes_restart_at_the_reached_address() {
+    let mut log = [0; 256];
+    let mut bus = Chunked::new(Recorder::new(Registers::new(), &mut log), 2);
+
+    bus.write_bytes(&[0x10, 1, 2, 3, 4, 5]).unwrap();
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [
+            (TAG_WRITE, Some(0x10), 3),
+            (TAG_WRITE, Some(0x12), 3),
+            (TAG_WRITE, Some(0x14), 2),
+        ]
+    );
+    assert_eq!(bus.bus.bus.regs[0x10..0x15], [1, 2, 3, 4, 5]);
+
+    // Chunks larger than the stack buffer are capped at MAX_WRITE_CHUNK
+    bus.bus.clear();
+    bus.set_max_len(64);
+    let mut wbuf = [0; 71];
+    wbuf[0] = 0x10;
+    bus.write_bytes(&wbuf).unwrap();
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [
+            (TAG_WRITE, Some(0x10), MAX_WRITE_CHUNK + 1),
+            (TAG_WRITE, Some(0x30), MAX_WRITE_CHUNK + 1),
+            (TAG_WRITE, Some(0x50), 7),
+        ]
+    );
+}
+
+#[test]
+fn write_then_read_splits_only_in_separate_mode() {
+    let mut log = [0; 64];
+    let mut bus = WriteThenRead::new(
+        Recorder::new(Registers::new(), &mut log),
+        ReadMode::Combined,
+    );
+    bus.bus.bus.regs[0x0F] = 0xB4;
+
+    let mut rbuf = [0; 1];
+    bus.write_byte_read_bytes(&[0x0F], &mut rbuf).unwrap();
+    assert_eq!(rbuf, [0xB4]);
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [(TAG_WRITE_READ, Some(0x0F), 1)]
+    );
+
+    let mut bus = WriteThenRead::new(bus.into_inner(), ReadMode::Separate);
+    bus.bus.clear();
+    let mut rbuf = [0; 1];
+    bus.write_byte_read_bytes(&[0x0F], &mut rbuf).unwrap();
+    assert_eq!(rbuf, [0xB4]);
+    assert_eq!(
+        transactions(bus.bus.recorded()),
+        [(TAG_WRITE, Some(0x0F), 1), (TAG_READ, None, 1)]
+    );
+}
+
+/// Events seen by the SPI bus and chip select doubles, in order.
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Low,
+    High,
+    Write(Vec<u8>),
+    Read(usize),
+    Flush,
+}
+
+struct Spi<'a> {
+    events: &'a RefCell<Vec<Event>>,
+    fail: bool,
+}
+
+impl spi::ErrorType for Spi<'_> {
+    type Error = spi::ErrorKind;
+}
+
+impl SpiBus for Spi<'_> {
+    fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        self.events.borrow_mut().push(Event::Read(words.len()));
+        if self.fail {
+            return Err(spi::ErrorKind::Other);
+        }
+        words.fill(0xB4);
+        Ok(())
+    }
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.events.borrow_mut().push(Event::Write(words.to_vec()));
+        Ok(())
+    }
+
+    fn transfer(&mut self, _read: &mut [u8], _write: &[u8]) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+
+    fn transfer_in_place(&mut self, _words: &mut [u8]) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.events.borrow_mut().push(Event::Flush);
+        Ok(())
+    }
+}
+
+struct Cs<'a> {
+    events: &'a RefCell<Vec<Event>>,
+}
+
+impl digital::ErrorType for Cs<'_> {
+    type Error = Infallible;
+}
+
+impl OutputPin for Cs<'_> {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.events.borrow_mut().push(Event::Low);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.events.borrow_mut().push(Event::High);
+        Ok(())
+    }
+}
+
+#[test]
+fn soft_cs_frames_each_transaction() {
+    let events = RefCell::new(Vec::new());
+    let mut bus = SoftCsSpi::new(
+        Spi {
+            events: &events,
+            fail: false,
+        },
+        Cs { events: &events },
+    );
+
+    let mut rbuf = [0; 2];
+    bus.write_byte_read_bytes(&[0x0F], &mut rbuf).unwrap();
+    assert_eq!(rbuf, [0xB4; 2]);
+    bus.write_bytes(&[0x10, 0x20]).unwrap();
+    assert_eq!(
+        events.take(),
+        [
+            Event::Low,
+            Event::Write(vec![0x8F]),
+            Event::Read(2),
+            Event::Flush,
+            Event::High,
+            Event::Low,
+            Event::Write(vec![0x10, 0x20]),
+            Event::Flush,
+            Event::High,
+        ]
+    );
+
+    // The chip select is released when the transfer fails
+    bus.spi.fail = true;
+    assert!(matches!(
+        bus.write_byte_read_bytes(&[0x0F], &mut rbuf),
+        Err(SoftCsError::Spi(spi::ErrorKind::Other))
+    ));
+    assert_eq!(
+        events.take(),
+        [
+            Event::Low,
+            Event::Write(vec![0x8F]),
+            Event::Read(2),
+            Event::High,
+        ]
+    );
+}
@@ -0,0 +1,52 @@
+//! Test doubles shared by the integration tests.
+
+use core::convert::Infallible;
+use ilps22qs_rs::blocking::BusOperation;
+use ilps22qs_rs::blocking::prelude::CtrlReg3;
+
+/// Register file standing in for the device, with address auto-increment.
+pub struct Registers {
+    pub regs: [u8; 0x80],
+    address: usize,
+}
+
+impl Registers {
+    pub fn new() -> Self {
+        let mut regs = [0; 0x80];
+        // CTRL_REG3 resets with IF_ADD_INC set.
+        regs[0x12] = CtrlReg3::new().with_if_add_inc(1).into_bits();
+        Self { regs, address: 0 }
+    }
+}
+
+impl BusOperation for Registers {
+    type Error = Infallible;
+
+    fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        for byte in rbuf {
+            *byte = self.regs[self.address];
+            self.address += 1;
+        }
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        if let Some((&address, data)) = wbuf.split_first() {
+            self.address = usize::from(address);
+            for &byte in data {
+                self.regs[self.address] = byte;
+                self.address += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.address = usize::from(wbuf[0]);
+        self.read_bytes(rbuf)
+    }
+}
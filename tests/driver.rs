@@ -0,0 +1,347 @@
+//! Behaviour of the driver beyond the register encodings, checked against a register file
+//! standing in for the device.
+//!
+//! The flags are placed with the register types, so the tests hold for both bit orders.
+
+#![cfg(all(feature = "blocking", feature = "float", feature = "qvar"))]
+
+mod common;
+
+use common::Registers;
+use embedded_hal::delay::DelayNs;
+use ilps22qs_rs::blocking::compat::{ilps22qs_data_get, ilps22qs_fifo_data_get};
+use ilps22qs_rs::blocking::prelude::*;
+use ilps22qs_rs::blocking::{BusOperation, Error, Ilps22qs};
+
+/// Delay returning immediately, the register file being always ready.
+struct NoDelay;
+
+impl DelayNs for NoDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Returns the byte with the single bit for which `is_set` holds, in the enabled bit order.
+fn flag(is_set: impl Fn(u8) -> bool) -> u8 {
+    (0..8)
+        .map(|bit| 1 << bit)
+        .find(|&byte| is_set(byte))
+        .unwrap()
+}
+
+/// Register file failing the next `failures` transactions.
+struct Flaky {
+    registers: Registers,
+    failures: u8,
+}
+
+impl Flaky {
+    fn fail(&mut self) -> Result<(), ()> {
+        if self.failures == 0 {
+            return Ok(());
+        }
+        self.failures -= 1;
+        Err(())
+    }
+}
+
+impl BusOperation for Flaky {
+    type Error = ();
+
+    fn read_bytes(&mut self, rbuf: &mut [u8]) -> Result<(), Self::Error> {
+        self.fail()?;
+        self.registers.read_bytes(rbuf).unwrap();
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, wbuf: &[u8]) -> Result<(), Self::Error> {
+        self.fail()?;
+        self.registers.write_bytes(wbuf).unwrap();
+        Ok(())
+    }
+
+    fn write_byte_read_bytes(
+        &mut self,
+        wbuf: &[u8; 1],
+        rbuf: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.fail()?;
+        self.registers.write_byte_read_bytes(wbuf, rbuf).unwrap();
+        Ok(())
+    }
+}
+
+#[test]
+fn recovery_policy_counts_consecutive_bus_errors() {
+    let mut registers = Registers::new();
+    registers.regs[0x0F] = 0xB4;
+    let mut sensor = Ilps22qs::from_bus(Flaky {
+        registers,
+        failures: 0,
+    });
+    sensor.recovery_policy_set(Some(RecoveryPolicy {
+        max_errors: 2,
+        action: RecoveryAction::Fatal,
+    }));
+
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.data_get_raw(), Err(Error::Bus(_))));
+    assert_eq!(sensor.bus_errors_get(), 1);
+    assert!(sensor.data_get_raw().is_ok());
+    assert_eq!(sensor.bus_errors_get(), 0);
+
+    sensor.bus.failures = 3;
+    assert!(matches!(sensor.data_get_raw(), Err(Error::Bus(_))));
+    assert!(matches!(sensor.data_get_raw(), Err(Error::Fatal)));
+    assert!(matches!(sensor.data_get_raw(), Err(Error::Fatal)));
+    assert!(sensor.data_get_raw().is_ok());
+
+    // The probe succeeds once the bus is back, restarting the count
+    sensor.recovery_policy_set(Some(RecoveryPolicy {
+        max_errors: 1,
+        action: RecoveryAction::Reprobe,
+    }));
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.data_get_raw(), Err(Error::Bus(_))));
+    assert_eq!(sensor.bus_errors_get(), 0);
+}
+
+#[test]
+fn recovery_policy_covers_fast_and_decimated_reads() {
+    let mut sensor = Ilps22qs::from_bus(Flaky {
+        registers: Registers::new(),
+        failures: 0,
+    });
+    sensor.recovery_policy_set(Some(RecoveryPolicy {
+        max_errors: 1,
+        action: RecoveryAction::Fatal,
+    }));
+
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.data_get_fast(), Err(Error::Fatal)));
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.pressure_get_fast(), Err(Error::Fatal)));
+    sensor.bus.failures = 1;
+    let mut data = [FifoData::default(); 2];
+    assert!(matches!(
+        sensor.fifo_data_get_decimated(4, 2, &mut data),
+        Err(Error::Fatal)
+    ));
+    assert_eq!(sensor.fifo_data_get_decimated(4, 2, &mut data).unwrap(), 2);
+    assert_eq!(sensor.bus_errors_get(), 0);
+}
+
+#[test]
+fn recovery_policy_covers_temperature_qvar_and_tagged_reads() {
+    let mut sensor = Ilps22qs::from_bus(Flaky {
+        registers: Registers::new(),
+        failures: 0,
+    });
+    sensor.recovery_policy_set(Some(RecoveryPolicy {
+        max_errors: 1,
+        action: RecoveryAction::Fatal,
+    }));
+
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.temp_data_get(), Err(Error::Fatal)));
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.ah_qvar_data_get(), Err(Error::Fatal)));
+    // The STATUS read comes first
+    sensor.bus.failures = 1;
+    assert!(matches!(sensor.data_get_tagged(), Err(Error::Fatal)));
+    assert!(sensor.data_get_tagged().is_ok());
+    assert_eq!(sensor.bus_errors_get(), 0);
+}
+
+#[test]
+fn probe_matches_who_am_i() {
+    let mut bus = Registers::new();
+    assert!(!Ilps22qs::probe(&mut bus).unwrap());
+    bus.regs[0x0F] = 0xB4;
+    assert!(Ilps22qs::probe(&mut bus).unwrap());
+}
+
+#[test]
+fn reset_recover_reapplies_configuration() {
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    let md = Md {
+        fs: Fs::_4060hpa,
+        odr: Odr::_25hz,
+        ..Md::default()
+    };
+    sensor.mode_set(&md).unwrap();
+    let configured = sensor.bus.regs;
+    assert!(sensor.reset_check().is_ok());
+    assert!(!sensor.reset_recover().unwrap());
+
+    sensor.bus = Registers::new();
+    assert!(matches!(sensor.reset_check(), Err(Error::DeviceReset)));
+    assert!(sensor.reset_recover().unwrap());
+    assert_eq!(sensor.bus.regs[0x10..0x13], configured[0x10..0x13]);
+    assert!(sensor.reset_check().is_ok());
+}
+
+#[test]
+fn mode_get_keeps_applied_mode_until_adopted() {
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    let md = Md {
+        fs: Fs::_4060hpa,
+        odr: Odr::_25hz,
+        ..Md::default()
+    };
+    sensor.mode_set(&md).unwrap();
+
+    sensor.bus = Registers::new();
+    assert!(sensor.mode_get().unwrap() == Md::default());
+    assert!(sensor.active_mode() == md);
+    assert!(matches!(sensor.reset_check(), Err(Error::DeviceReset)));
+
+    assert!(sensor.mode_adopt().unwrap() == Md::default());
+    assert!(sensor.active_mode() == Md::default());
+    assert!(sensor.reset_check().is_ok());
+}
+
+#[test]
+fn tagged_samples_carry_sequence_and_flags() {
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    let p_da = flag(|byte| Status::from_bits(byte).p_da() == 1);
+    let p_or = flag(|byte| Status::from_bits(byte).p_or() == 1);
+    let mut tagged = |status| {
+        sensor.bus.regs[0x27] = status;
+        let sample = sensor.data_get_tagged().unwrap();
+        (sample.seq, sample.stale, sample.overrun)
+    };
+    assert_eq!(tagged(p_da), (1, false, false));
+    assert_eq!(tagged(0x00), (1, true, false));
+    assert_eq!(tagged(p_da | p_or), (2, false, true));
+
+    // FIFO_OVR_IA flags the first drained sample only.
+    sensor.bus.regs[0x26] = flag(|byte| FifoStatus2::from_bits(byte).fifo_ovr_ia() == 1);
+    let mut data = [Sample::<FifoData>::default(); 3];
+    assert_eq!(sensor.fifo_data_get_tagged(3, &mut data).unwrap(), 3);
+    let flags: Vec<_> = data.iter().map(|s| (s.seq, s.stale, s.overrun)).collect();
+    assert_eq!(
+        flags,
+        [(3, false, true), (4, false, false), (5, false, false)]
+    );
+}
+
+#[test]
+fn fifo_stats_track_fill_levels() {
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    for level in [10, 40, 25] {
+        sensor.bus.regs[0x25] = level;
+        assert_eq!(sensor.fifo_level_get().unwrap(), level);
+    }
+    let stats = sensor.fifo_stats_get();
+    assert_eq!(stats.drains, 3);
+    assert_eq!(stats.high_water, 40);
+    assert_eq!(stats.average(), Some(25.0));
+
+    sensor.fifo_stats_reset();
+    assert_eq!(sensor.fifo_stats_get(), FifoStats::default());
+}
+
+#[test]
+fn user_correction_applies_to_converted_values() {
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    // PRESS_OUT = 0x400000, i.e. 1024 hPa at the 1260 hPa full scale.
+    sensor.bus.regs[0x28..0x2B].copy_from_slice(&[0x00, 0x00, 0x40]);
+    // TEMP_OUT = 2500, i.e. 25 °C.
+    sensor.bus.regs[0x2B..0x2D].copy_from_slice(&2500i16.to_le_bytes());
+
+    sensor.user_correction_set(UserCorrection {
+        pressure: Some(Correction::Function(|hpa, deg_c| hpa + deg_c)),
+        temperature: Some(Correction::Polynomial([1.0, 2.0, 0.0, 0.0])),
+    });
+    let data = sensor.data_get().unwrap();
+    assert_eq!(data.heat.deg_c, 51.0);
+    assert_eq!(data.pressure.hpa, 1024.0 + 51.0);
+
+    sensor.user_correction_set(UserCorrection::default());
+    assert_eq!(sensor.data_get().unwrap().pressure.hpa, 1024.0);
+}
+
+#[test]
+fn two_point_calibration_maps_measured_to_reference() {
+    let point = |measured_hpa, reference_hpa| CalibrationPoint {
+        measured_hpa,
+        reference_hpa,
+    };
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    let calibration = sensor
+        .calibrate_two_point(point(301.0, 300.0), point(1101.0, 1100.0))
+        .unwrap();
+    assert_eq!(calibration.pressure_gain, 1.0);
+    assert_eq!(calibration.pressure_offset_hpa, -1.0);
+
+    // The second fit is measured with the first in place, so the two cancel out.
+    let calibration = sensor
+        .calibrate_two_point(point(299.0, 300.0), point(1099.0, 1100.0))
+        .unwrap();
+    assert_eq!(calibration.pressure_correct(1.0), 1.0);
+    assert!(
+        sensor
+            .calibrate_two_point(point(500.0, 300.0), point(500.0, 1100.0))
+            .is_none()
+    );
+}
+
+#[test]
+fn calibrate_offset_uses_applied_mode() {
+    let mut sensor = Ilps22qs::from_bus(Registers::new());
+    sensor
+        .mode_set(&Md {
+            fs: Fs::_4060hpa,
+            ..Md::default()
+        })
+        .unwrap();
+    // PRESS_OUT = 0x400000, i.e. 2048 hPa at the 4060 hPa full scale, with P_DA set.
+    sensor.bus.regs[0x28..0x2B].copy_from_slice(&[0x00, 0x00, 0x40]);
+    sensor.bus.regs[0x27] = flag(|byte| Status::from_bits(byte).p_da() == 1);
+
+    // 8 hPa above the reference, at 8 LSB/hPa.
+    assert_eq!(
+        sensor.calibrate_offset(&mut NoDelay, 2040.0, 4).unwrap(),
+        64
+    );
+    assert_eq!(sensor.opc_get().unwrap(), 64);
+}
+
+#[test]
+fn compat_conversion_keeps_applied_mode_for_recovery() {
+    let mut registers = Registers::new();
+    registers.regs[0x0F] = 0xB4;
+    let mut sensor = Ilps22qs::from_bus(Flaky {
+        registers,
+        failures: 0,
+    });
+    let md = Md {
+        fs: Fs::_4060hpa,
+        odr: Odr::_25hz,
+        ..Md::default()
+    };
+    sensor.mode_set(&md).unwrap();
+    let configured = sensor.bus.registers.regs;
+    sensor.recovery_policy_set(Some(RecoveryPolicy {
+        max_errors: 1,
+        action: RecoveryAction::Reinit,
+    }));
+
+    // The reinit triggered while converting with the caller's parameters restores the applied ones
+    sensor.bus.failures = 1;
+    let mut data = Data::default();
+    assert!(ilps22qs_data_get(&mut sensor, &Md::default(), &mut data).is_err());
+    assert_eq!(
+        sensor.bus.registers.regs[0x10..0x13],
+        configured[0x10..0x13]
+    );
+
+    sensor.bus.failures = 1;
+    let mut fifo = [FifoData::default(); 2];
+    assert!(ilps22qs_fifo_data_get(&mut sensor, 2, &Md::default(), &mut fifo).is_err());
+    assert_eq!(
+        sensor.bus.registers.regs[0x10..0x13],
+        configured[0x10..0x13]
+    );
+    assert!(sensor.active_mode() == md);
+}
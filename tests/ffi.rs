@@ -0,0 +1,190 @@
+//! C ABI of the `ffi` module, driven through `extern "C"` callbacks backed by a register file
+//! standing in for the device.
+
+#![cfg(feature = "ffi")]
+
+mod common;
+
+use common::Registers;
+use core::ffi::c_void;
+use core::ptr;
+use ilps22qs_rs::blocking::BusOperation;
+use ilps22qs_rs::blocking::prelude::CtrlReg2;
+use ilps22qs_rs::ffi::*;
+
+unsafe extern "C" fn write_reg(handle: *mut c_void, reg: u8, data: *const u8, len: u16) -> i32 {
+    // SAFETY: the handle is the register file of the test, and `data` holds `len` bytes.
+    let (registers, data) = unsafe {
+        (
+            &mut *handle.cast::<Registers>(),
+            core::slice::from_raw_parts(data, len.into()),
+        )
+    };
+    let wbuf: Vec<u8> = [reg].iter().chain(data).copied().collect();
+    registers.write_bytes(&wbuf).unwrap();
+
+    // The software reset completes at once
+    let ctrl_reg2 = CtrlReg2::from_bits(registers.regs[0x11]);
+    registers.regs[0x11] = ctrl_reg2.with_swreset(0).into_bits();
+    0
+}
+
+unsafe extern "C" fn read_reg(handle: *mut c_void, reg: u8, data: *mut u8, len: u16) -> i32 {
+    // SAFETY: the handle is the register file of the test, and `data` holds `len` bytes.
+    let (registers, data) = unsafe {
+        (
+            &mut *handle.cast::<Registers>(),
+            core::slice::from_raw_parts_mut(data, len.into()),
+        )
+    };
+    registers.write_byte_read_bytes(&[reg], data).unwrap();
+    0
+}
+
+unsafe extern "C" fn mdelay(_ms: u32) {}
+
+fn context(registers: &mut Registers) -> Ilps22qsCtx {
+    Ilps22qsCtx {
+        write_reg: Some(write_reg),
+        read_reg: Some(read_reg),
+        mdelay: Some(mdelay),
+        handle: ptr::from_mut(registers).cast(),
+    }
+}
+
+#[test]
+fn init_checks_the_id_and_prepares_the_device() {
+    let mut registers = Registers::new();
+    let ctx = context(&mut registers);
+    assert_eq!(unsafe { ilps22qs_rs_init(&ctx) }, ILPS22QS_RS_ERR_ID);
+
+    registers.regs[0x0F] = 0xB4;
+    let ctx = context(&mut registers);
+    assert_eq!(unsafe { ilps22qs_rs_init(&ctx) }, ILPS22QS_RS_OK);
+    assert_eq!(CtrlReg2::from_bits(registers.regs[0x11]).bdu(), 1);
+
+    let ctx = Ilps22qsCtx {
+        read_reg: None,
+        ..context(&mut registers)
+    };
+    assert_eq!(unsafe { ilps22qs_rs_init(&ctx) }, ILPS22QS_RS_ERR_BUS);
+}
+
+#[test]
+fn mode_round_trips_through_the_registers() {
+    let mut registers = Registers::new();
+    let ctx = context(&mut registers);
+    let md = Ilps22qsMd {
+        interleaved_mode: 0,
+        fs: 1,
+        odr: 4,
+        avg: 2,
+        lpf: 1,
+    };
+    assert_eq!(unsafe { ilps22qs_rs_mode_set(&ctx, &md) }, ILPS22QS_RS_OK);
+
+    let mut read = Ilps22qsMd::default();
+    assert_eq!(
+        unsafe { ilps22qs_rs_mode_get(&ctx, &mut read) },
+        ILPS22QS_RS_OK
+    );
+    assert_eq!(read, md);
+}
+
+#[test]
+fn data_get_converts_with_the_given_mode() {
+    let mut registers = Registers::new();
+    // PRESS_OUT = 0x400000, i.e. 1024 hPa at the 1260 hPa full scale.
+    registers.regs[0x28..0x2B].copy_from_slice(&[0x00, 0x00, 0x40]);
+    // TEMP_OUT = 2500, i.e. 25 °C.
+    registers.regs[0x2B..0x2D].copy_from_slice(&2500i16.to_le_bytes());
+    let ctx = context(&mut registers);
+
+    let mut data = Ilps22qsData::default();
+    let md = Ilps22qsMd::default();
+    assert_eq!(
+        unsafe { ilps22qs_rs_data_get(&ctx, &md, &mut data) },
+        ILPS22QS_RS_OK
+    );
+    assert_eq!(data.pressure_hpa, 1024.0);
+    assert_eq!(data.temperature_deg_c, 25.0);
+    assert_eq!(data.temperature_raw, 2500);
+
+    let md = Ilps22qsMd { fs: 1, ..md };
+    assert_eq!(
+        unsafe { ilps22qs_rs_data_get(&ctx, &md, &mut data) },
+        ILPS22QS_RS_OK
+    );
+    assert_eq!(data.pressure_hpa, 2048.0);
+}
+
+#[test]
+fn null_pointers_are_rejected() {
+    let mut registers = Registers::new();
+    let ctx = context(&mut registers);
+    let md = Ilps22qsMd::default();
+    let mut read = Ilps22qsMd::default();
+    let mut data = Ilps22qsData::default();
+
+    unsafe {
+        assert_eq!(ilps22qs_rs_init(ptr::null()), ILPS22QS_RS_ERR_ARG);
+        assert_eq!(ilps22qs_rs_mode_set(ptr::null(), &md), ILPS22QS_RS_ERR_ARG);
+        assert_eq!(ilps22qs_rs_mode_set(&ctx, ptr::null()), ILPS22QS_RS_ERR_ARG);
+        assert_eq!(
+            ilps22qs_rs_mode_get(ptr::null(), &mut read),
+            ILPS22QS_RS_ERR_ARG
+        );
+        assert_eq!(
+            ilps22qs_rs_mode_get(&ctx, ptr::null_mut()),
+            ILPS22QS_RS_ERR_ARG
+        );
+        assert_eq!(
+            ilps22qs_rs_data_get(ptr::null(), &md, &mut data),
+            ILPS22QS_RS_ERR_ARG
+        );
+        assert_eq!(
+            ilps22qs_rs_data_get(&ctx, ptr::null(), &mut data),
+            ILPS22QS_RS_ERR_ARG
+        );
+        assert_eq!(
+            ilps22qs_rs_data_get(&ctx, &md, ptr::null_mut()),
+            ILPS22QS_RS_ERR_ARG
+        );
+    }
+}
+
+#[test]
+fn out_of_range_modes_are_rejected_without_bus_access() {
+    let mut registers = Registers::new();
+    let ctx = context(&mut registers);
+    let mut data = Ilps22qsData::default();
+
+    for md in [
+        Ilps22qsMd {
+            fs: 2,
+            ..Default::default()
+        },
+        Ilps22qsMd {
+            odr: 9,
+            ..Default::default()
+        },
+        Ilps22qsMd {
+            avg: 8,
+            ..Default::default()
+        },
+        Ilps22qsMd {
+            lpf: 2,
+            ..Default::default()
+        },
+    ] {
+        assert_eq!(
+            unsafe { ilps22qs_rs_mode_set(&ctx, &md) },
+            ILPS22QS_RS_ERR_ARG
+        );
+        assert_eq!(
+            unsafe { ilps22qs_rs_data_get(&ctx, &md, &mut data) },
+            ILPS22QS_RS_ERR_ARG
+        );
+    }
+    assert_eq!(registers.regs[0x10..0x12], [0, 0]);
+}
@@ -0,0 +1,345 @@
+//! Conversion and register encoding vectors generated with ST's reference `ilps22qs_reg.c`.
+//!
+//! The C driver builds the raw pressure as `((buff[2] * 256 + buff[1]) * 256 + buff[0]) * 256`
+//! and converts it with `lsb / 1048576.0f` (1260 hPa) or `lsb / 524288.0f` (4060 hPa). The
+//! temperature is `lsb / 100.0f` and the AH/QVAR voltage `lsb / 438000.0f`. In interleaved mode
+//! a sample with bit 0 of `buff[0]` set is an AH/QVAR sample whose value is `raw / 256`.
+//!
+//! The expected values are the exact `float` results of the C code, so they are compared bit for
+//! bit. The register encodings are checked against both the bitfields and, with the `blocking`
+//! feature, the registers written by the driver.
+//!
+//! The vectors cover the conversions and register layouts only: the stages the crate adds on top
+//! of the C code, such as software calibration and filtering, are covered by `tests/driver.rs`.
+//! The interleaved split follows the C code, which the crate did not until the AH/QVAR flag was
+//! moved from bit 0 to bit 8 of the left-aligned raw value, see
+//! `interleaved_samples_split_like_reference`.
+
+#![cfg(all(feature = "float", feature = "qvar"))]
+
+#[cfg(not(feature = "blocking"))]
+use ilps22qs_rs::asynchronous as flavor;
+#[cfg(feature = "blocking")]
+use ilps22qs_rs::blocking as flavor;
+
+#[cfg(all(feature = "blocking", not(feature = "bit_order_msb")))]
+mod common;
+
+use flavor::prelude::*;
+use flavor::{
+    decode, from_fs1260_to_hpa, from_fs4000_to_hpa, from_lsb_to_celsius, from_lsb_to_hpa,
+    from_lsb_to_mv,
+};
+
+/// PRESS_OUT_XL, PRESS_OUT_L, PRESS_OUT_H, raw value, hPa at 1260 hPa, hPa at 4060 hPa.
+const PRESSURE: [([u8; 3], i32, f32, f32); 8] = [
+    ([0x00, 0x00, 0x00], 0, 0.0, 0.0),
+    ([0x00, 0x00, 0x40], 1_073_741_824, 1024.0, 2048.0),
+    ([0x00, 0xD0, 0x3E], 1_053_818_880, 1005.0, 2010.0),
+    ([0x14, 0x8E, 0x3F], 1_066_275_840, 1_016.879_9, 2_033.759_8),
+    ([0xFE, 0xFF, 0x7F], 2_147_483_136, 2_047.999_5, 4_095.999),
+    ([0x00, 0x00, 0x80], -2_147_483_648, -2048.0, -4096.0),
+    ([0x9A, 0x21, 0xFC], -64_906_752, -61.899_902, -123.799_805),
+    ([0x02, 0x00, 0x00], 512, 0.000_488_281_25, 0.000_976_562_5),
+];
+
+/// TEMP_OUT, °C.
+const TEMPERATURE: [(i16, f32); 6] = [
+    (0, 0.0),
+    (2500, 25.0),
+    (-4000, -40.0),
+    (32_767, 327.67),
+    (-32_768, -327.68),
+    (1234, 12.34),
+];
+
+/// AH/QVAR LSB, mV.
+const AH_QVAR: [(i32, f32); 6] = [
+    (0, 0.0),
+    (438_000, 1.0),
+    (-219_000, -0.5),
+    (8_388_607, 19.152_071),
+    (-8_388_608, -19.152_073),
+    (12_345, 0.028_184_932),
+];
+
+fn md(fs: Fs, interleaved_mode: bool) -> Md {
+    Md {
+        interleaved_mode,
+        fs,
+        odr: Odr::_25hz,
+        avg: Avg::_16,
+        lpf: Lpf::Disable,
+    }
+}
+
+fn assert_bits(actual: f32, expected: f32, what: &str) {
+    assert_eq!(
+        actual.to_bits(),
+        expected.to_bits(),
+        "{what}: {actual} != {expected}"
+    );
+}
+
+#[test]
+fn pressure_matches_reference() {
+    for (bytes, raw, fs1260, fs4060) in PRESSURE {
+        assert_eq!(decode::raw_pressure_from_bytes(bytes), raw, "{bytes:02X?}");
+        assert_bits(from_fs1260_to_hpa(raw), fs1260, "fs1260");
+        assert_bits(from_fs4000_to_hpa(raw), fs4060, "fs4060");
+        assert_bits(
+            from_lsb_to_hpa(Fs::_1260hpa, raw),
+            fs1260,
+            "from_lsb_to_hpa",
+        );
+        assert_bits(
+            from_lsb_to_hpa(Fs::_4060hpa, raw),
+            fs4060,
+            "from_lsb_to_hpa",
+        );
+        assert_bits(
+            decode::hpa_from_raw(raw, Fs::_1260hpa),
+            fs1260,
+            "hpa_from_raw",
+        );
+        assert_bits(
+            decode::hpa_from_raw(raw, Fs::_4060hpa),
+            fs4060,
+            "hpa_from_raw",
+        );
+    }
+}
+
+#[test]
+fn sensitivity_matches_reference() {
+    // 16-bit THS_P, REF_P and RPDS registers: 16 LSB/hPa at 1260 hPa, 8 LSB/hPa at 4060 hPa
+    for (fs, raw, reg16) in [(Fs::_1260hpa, 1 << 20, 16), (Fs::_4060hpa, 1 << 19, 8)] {
+        let sensitivity = fs.sensitivity();
+        assert_eq!(sensitivity.raw_lsb_per_hpa(), raw);
+        assert_eq!(sensitivity.reg16_lsb_per_hpa(), reg16);
+        assert_bits(
+            sensitivity.reg16_to_hpa(16_000),
+            16_000.0 / reg16 as f32,
+            "reg16",
+        );
+        assert_bits(
+            sensitivity.hpa_to_reg16(1000.0),
+            1000.0 * reg16 as f32,
+            "reg16",
+        );
+    }
+}
+
+#[test]
+fn temperature_matches_reference() {
+    for (lsb, deg_c) in TEMPERATURE {
+        assert_bits(from_lsb_to_celsius(lsb), deg_c, "temperature");
+    }
+}
+
+#[test]
+fn ah_qvar_matches_reference() {
+    for (lsb, mv) in AH_QVAR {
+        assert_bits(from_lsb_to_mv(lsb), mv, "ah/qvar");
+    }
+}
+
+#[test]
+fn output_registers_decode_like_reference() {
+    for (fs, column) in [(Fs::_1260hpa, 0), (Fs::_4060hpa, 1)] {
+        for (bytes, raw, fs1260, fs4060) in PRESSURE {
+            let [xl, l, h] = bytes;
+            let data = decode::data_from_bytes([xl, l, h, 0xC4, 0x09], &md(fs, false));
+            assert_eq!(data.pressure.raw, raw);
+            assert_bits(data.pressure.hpa, [fs1260, fs4060][column], "data");
+            assert_bits(data.heat.deg_c, 25.0, "data");
+
+            let fifo = decode::fifo_data_from_bytes(bytes, &md(fs, false));
+            assert_eq!(fifo.raw, raw);
+            assert_bits(fifo.hpa, [fs1260, fs4060][column], "fifo");
+        }
+    }
+}
+
+#[test]
+fn interleaved_samples_split_like_reference() {
+    let md = md(Fs::_1260hpa, true);
+
+    // Pressure samples have bit 0 of PRESS_OUT_XL cleared. The crate used to test bit 0 of the
+    // left-aligned raw value instead, which is always clear, so every sample was taken for
+    // pressure.
+    let data = decode::data_from_bytes([0x14, 0x8E, 0x3F, 0x00, 0x00], &md);
+    assert_bits(data.pressure.hpa, 1_016.879_9, "pressure");
+    assert_eq!(data.ah_qvar.lsb, 0);
+
+    // AH/QVAR samples have it set, and carry `raw / 256`.
+    for bytes in [[0x01, 0x00, 0x00], [0x15, 0x8E, 0x3F], [0xFF, 0xFF, 0xFF]] {
+        let raw = decode::raw_pressure_from_bytes(bytes);
+        assert!(decode::is_ah_qvar_sample(raw, &md), "{bytes:02X?}");
+
+        let [xl, l, h] = bytes;
+        let data = decode::data_from_bytes([xl, l, h, 0x00, 0x00], &md);
+        assert_eq!(data.ah_qvar.lsb, raw / 256);
+        assert_bits(data.pressure.hpa, 0.0, "pressure");
+
+        let fifo = decode::fifo_data_from_bytes(bytes, &md);
+        assert_eq!(fifo.lsb, raw / 256);
+        assert_bits(fifo.hpa, 0.0, "fifo");
+    }
+
+    // Outside interleaved mode the flag is a plain pressure bit.
+    let raw = decode::raw_pressure_from_bytes([0x01, 0x00, 0x00]);
+    assert!(!decode::is_ah_qvar_sample(
+        raw,
+        &self::md(Fs::_1260hpa, false)
+    ));
+}
+
+// The reference layouts are those of the default LSB-first bit order.
+#[cfg(not(feature = "bit_order_msb"))]
+mod registers {
+    use super::*;
+
+    /// Expected CTRL_REG1 and CTRL_REG2 for `ilps22qs_mode_set`.
+    fn reference_ctrl_regs(md: &Md) -> [u8; 2] {
+        let lpf = md.lpf as u8;
+        let ctrl_reg1 = ((md.odr as u8) << 3) | md.avg as u8;
+        let ctrl_reg2 = ((md.fs as u8) << 6) | (((lpf & 0x02) >> 1) << 5) | ((lpf & 0x01) << 4);
+        [ctrl_reg1, ctrl_reg2]
+    }
+
+    const ODRS: [Odr; 9] = [
+        Odr::OneShot,
+        Odr::_1hz,
+        Odr::_4hz,
+        Odr::_10hz,
+        Odr::_25hz,
+        Odr::_50hz,
+        Odr::_75hz,
+        Odr::_100hz,
+        Odr::_200hz,
+    ];
+    const AVGS: [Avg; 8] = [
+        Avg::_4,
+        Avg::_8,
+        Avg::_16,
+        Avg::_32,
+        Avg::_64,
+        Avg::_128,
+        Avg::_256,
+        Avg::_512,
+    ];
+    const LPFS: [Lpf; 3] = [Lpf::Disable, Lpf::OdrDiv4, Lpf::OdrDiv9];
+
+    #[test]
+    fn register_fields_encode_like_reference() {
+        for odr in ODRS {
+            for avg in AVGS {
+                let md = Md {
+                    odr,
+                    avg,
+                    ..md(Fs::_1260hpa, false)
+                };
+                let ctrl_reg1 = CtrlReg1::new().with_odr(odr as u8).with_avg(avg as u8);
+                assert_eq!(ctrl_reg1.into_bits(), reference_ctrl_regs(&md)[0]);
+            }
+        }
+
+        for fs in [Fs::_1260hpa, Fs::_4060hpa] {
+            for lpf in LPFS {
+                let md = Md {
+                    lpf,
+                    ..md(fs, false)
+                };
+                let ctrl_reg2 = CtrlReg2::new()
+                    .with_en_lpfp(lpf as u8 & 0x01)
+                    .with_lfpf_cfg((lpf as u8 & 0x02) >> 1)
+                    .with_fs_mode(fs as u8);
+                assert_eq!(ctrl_reg2.into_bits(), reference_ctrl_regs(&md)[1]);
+            }
+        }
+
+        // `ilps22qs_fifo_mode_set`: f_mode = operation & 3, trig_modes = (operation & 4) >> 2.
+        for (operation, bits) in [
+            (Operation::Bypass, 0x00),
+            (Operation::Fifo, 0x01),
+            (Operation::Stream, 0x02),
+            (Operation::BypassToFifo, 0x05),
+            (Operation::BypassToStream, 0x06),
+            (Operation::StreamToFifo, 0x07),
+        ] {
+            let fifo_ctrl = FifoCtrl::new()
+                .with_f_mode(operation as u8 & 0x03)
+                .with_trig_modes((operation as u8 & 0x04) >> 2);
+            assert_eq!(fifo_ctrl.into_bits(), bits);
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    mod driver {
+        use super::*;
+        use crate::common::Registers;
+        use ilps22qs_rs::blocking::Ilps22qs;
+
+        #[test]
+        fn mode_set_writes_reference_registers() {
+            let mut sensor = Ilps22qs::from_bus(Registers::new());
+            for fs in [Fs::_1260hpa, Fs::_4060hpa] {
+                for lpf in LPFS {
+                    for odr in ODRS {
+                        for avg in AVGS {
+                            for interleaved_mode in [false, true] {
+                                let md = Md {
+                                    interleaved_mode,
+                                    fs,
+                                    odr,
+                                    avg,
+                                    lpf,
+                                };
+                                sensor.mode_set(&md).unwrap();
+
+                                let regs = &sensor.bus.regs;
+                                assert_eq!([regs[0x10], regs[0x11]], reference_ctrl_regs(&md));
+                                assert_eq!(regs[0x12], 0x01 | (u8::from(interleaved_mode) << 5));
+                                assert_eq!(regs[0x14] & 0x10, u8::from(interleaved_mode) << 4);
+                                assert!(sensor.mode_get().unwrap() == md);
+                                assert!(sensor.full_scale_get().unwrap() == fs);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn fifo_mode_set_writes_reference_registers() {
+            let mut sensor = Ilps22qs::from_bus(Registers::new());
+            for (watermark, stop_on_wtm) in [(0, 0x00), (16, 0x08), (127, 0x08)] {
+                let md = FifoMd {
+                    operation: Operation::StreamToFifo,
+                    watermark: Watermark::new(watermark).unwrap(),
+                };
+                sensor.fifo_mode_set(&md).unwrap();
+                assert_eq!(sensor.bus.regs[0x14], 0x07 | stop_on_wtm);
+                assert_eq!(sensor.bus.regs[0x15], watermark);
+            }
+        }
+
+        #[test]
+        fn data_get_converts_with_applied_full_scale() {
+            let mut sensor = Ilps22qs::from_bus(Registers::new());
+            for (bytes, _, fs1260, fs4060) in PRESSURE {
+                sensor.bus.regs[0x28..0x2B].copy_from_slice(&bytes);
+                for (fs, hpa) in [(Fs::_1260hpa, fs1260), (Fs::_4060hpa, fs4060)] {
+                    sensor
+                        .mode_set(&Md {
+                            fs,
+                            ..Md::default()
+                        })
+                        .unwrap();
+                    assert_bits(sensor.data_get().unwrap().pressure.hpa, hpa, "data_get");
+                }
+            }
+        }
+    }
+}